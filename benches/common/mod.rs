@@ -0,0 +1,38 @@
+//! Shared timing and input helpers for the benchmarks in this directory.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 20;
+
+pub fn bench<T>(name: &str, mut f: impl FnMut() -> T) {
+    let mut best = Duration::MAX;
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        black_box(f());
+        let elapsed = start.elapsed();
+        best = best.min(elapsed);
+        total += elapsed;
+    }
+    let mean = total / ITERATIONS;
+    println!("{name:<24} best {best:>12?}  mean {mean:>12?}");
+}
+
+/// A pseudo-random day04-style grid of `.` and `@`, `size` cells square.
+pub fn grid_input(size: usize) -> String {
+    let mut state: u32 = 0x2545_f491;
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    if state.is_multiple_of(3) { '.' } else { '@' }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,50 @@
+//! Times the grid-based solutions end to end, to track the cost of `Grid` storage and
+//! neighbour lookups.
+//!
+//! Uses the real puzzle inputs from `inputs/` when present, falling back to generated
+//! inputs of a similar shape. Run with `cargo bench --bench grid`.
+
+mod common;
+
+use aoc_lib::solutions::{Solution, day04, day07};
+use aoc_lib::utils::parser::Parser;
+use common::{bench, grid_input};
+use std::fs;
+
+fn input(day: u8, fallback: impl FnOnce() -> String) -> String {
+    match fs::read_to_string(format!("inputs/day{day:02}.txt")) {
+        Ok(input) => input.trim_end_matches("\n").to_string(),
+        Err(_) => fallback(),
+    }
+}
+
+/// A day07-style manifold: a source on the top row and staggered splitters on every
+/// other row below it.
+fn manifold_input(size: usize) -> String {
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| match (y, x) {
+                    (0, x) if x == size / 2 => 'S',
+                    (y, x) if y % 2 == 0 && y > 0 && (x * 7 + y * 3) % 11 == 0 => '^',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn run<S: Solution>(name: &str, sol: &S, input: &str) {
+    bench(&format!("{name} parse"), || {
+        sol.parser().parse(input).unwrap()
+    });
+    let parsed = sol.parser().parse(input).unwrap();
+    bench(&format!("{name} part 1"), || sol.part1(&parsed));
+    bench(&format!("{name} part 2"), || sol.part2(&parsed));
+}
+
+fn main() {
+    run("day04", &day04::Sol, &input(4, || grid_input(500)));
+    run("day07", &day07::Sol, &input(7, || manifold_input(500)));
+}
@@ -0,0 +1,40 @@
+//! Compares the `&str`/`char` parsers against their `&[u8]` fast-path counterparts.
+//!
+//! Run with `cargo bench --bench parsing`.
+
+mod common;
+
+use aoc_lib::char_match;
+use aoc_lib::utils::grid::Grid;
+use aoc_lib::utils::parser;
+use aoc_lib::utils::parser::{ByteSliceParser, ParseError, Parser, StrParser};
+use common::{bench, grid_input};
+
+/// One signed integer per line, `count` lines long.
+fn int_input(count: i64) -> String {
+    (0..count)
+        .map(|i| (i * 7919 - 500_000_000).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn main() {
+    let grid = grid_input(2000);
+    let str_grid = Grid::parser(char_match!('.' => false, '@' => true));
+    let byte_grid = Grid::parser_bytes(|b: u8| match b {
+        b'.' => Ok(false),
+        b'@' => Ok(true),
+        _ => Err(ParseError::Other(format!(
+            "Unexpected byte: '{}'",
+            b as char
+        ))),
+    });
+    bench("grid (str)", || str_grid.parse(&grid).unwrap());
+    bench("grid (bytes)", || byte_grid.parse(grid.as_bytes()).unwrap());
+
+    let ints = int_input(1_000_000);
+    let str_ints = parser::from_str::<i64>.lines();
+    let byte_ints = parser::from_ascii::<i64>.lines();
+    bench("ints (str)", || str_ints.parse(&ints).unwrap());
+    bench("ints (bytes)", || byte_ints.parse(ints.as_bytes()).unwrap());
+}
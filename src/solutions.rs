@@ -12,12 +12,13 @@ pub mod day10;
 use crate::utils::parser::Parser;
 
 pub trait Solution {
-    type Parsed;
+    /// The parsed puzzle input, which may borrow slices of the raw input for `'a`.
+    type Parsed<'a>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed>;
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>>;
 
-    fn part1(&self, parsed: &Self::Parsed) -> String;
-    fn part2(&self, parsed: &Self::Parsed) -> String;
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> String;
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> String;
 }
 
 #[cfg(test)]
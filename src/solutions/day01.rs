@@ -20,9 +20,9 @@ pub struct DialAction {
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = Vec<DialAction>;
+    type Parsed<'a> = Vec<DialAction>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         let parse_direction = char_match! {
             'L' => Direction::Left,
             'R' => Direction::Right,
@@ -35,7 +35,7 @@ impl Solution for Sol {
             .lines()
     }
 
-    fn part1(&self, actions: &Self::Parsed) -> String {
+    fn part1(&self, actions: &Self::Parsed<'_>) -> String {
         let mut result = 0;
         let mut curr = STARTING_NUMBER;
         for action in actions {
@@ -52,7 +52,7 @@ impl Solution for Sol {
         result.to_string()
     }
 
-    fn part2(&self, actions: &Self::Parsed) -> String {
+    fn part2(&self, actions: &Self::Parsed<'_>) -> String {
         let mut result = 0;
         let mut curr = STARTING_NUMBER;
         let mut was_zero = false;
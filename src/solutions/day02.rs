@@ -36,13 +36,13 @@ fn is_id_invalid_part2(id: &IdType) -> bool {
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = Vec<Range<IdType>>;
+    type Parsed<'a> = Vec<Range<IdType>>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         parser::from_str::<Range<IdType>>.split(",")
     }
 
-    fn part1(&self, ranges: &Self::Parsed) -> String {
+    fn part1(&self, ranges: &Self::Parsed<'_>) -> String {
         let mut result = 0;
         for range in ranges {
             result += range.iter().filter(is_id_invalid_part1).sum::<IdType>();
@@ -50,7 +50,7 @@ impl Solution for Sol {
         result.to_string()
     }
 
-    fn part2(&self, ranges: &Self::Parsed) -> String {
+    fn part2(&self, ranges: &Self::Parsed<'_>) -> String {
         let mut result = 0;
         for range in ranges {
             result += range.iter().filter(is_id_invalid_part2).sum::<IdType>();
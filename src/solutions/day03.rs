@@ -34,17 +34,17 @@ fn sum_of_largest_joltages(digit_lines: &Vec<Vec<u32>>, num_batteries: usize) ->
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = Vec<Vec<u32>>;
+    type Parsed<'a> = Vec<Vec<u32>>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         parser::digit::<10>.chars().lines()
     }
 
-    fn part1(&self, digit_lines: &Self::Parsed) -> String {
+    fn part1(&self, digit_lines: &Self::Parsed<'_>) -> String {
         sum_of_largest_joltages(digit_lines, 2).to_string()
     }
 
-    fn part2(&self, digit_lines: &Self::Parsed) -> String {
+    fn part2(&self, digit_lines: &Self::Parsed<'_>) -> String {
         sum_of_largest_joltages(digit_lines, 12).to_string()
     }
 }
@@ -1,6 +1,6 @@
 use crate::char_match;
 use crate::solutions::Solution;
-use crate::utils::grid::{Grid, GridPosition};
+use crate::utils::grid::{BitGrid, Grid};
 use crate::utils::parser::Parser;
 
 #[derive(PartialEq, Copy, Clone)]
@@ -9,25 +9,21 @@ pub enum Square {
     Paper,
 }
 
-fn get_accessible_paper_positions(grid: &Grid<Square>) -> impl Iterator<Item = GridPosition> {
-    grid.iter_enumerated().filter_map(|(pos, square)| {
-        let is_accessible = *square == Square::Paper
-            && grid
-                .surrounding_cells(pos)
-                .filter(|&cell| *cell == Square::Paper)
-                .count()
-                < 4;
+/// A roll of paper stays put unless fewer than four of its neighbours are paper.
+fn stays(paper: bool, neighbours: usize) -> bool {
+    paper && neighbours >= 4
+}
 
-        is_accessible.then_some(pos)
-    })
+fn paper_bits(grid: &Grid<Square>) -> BitGrid {
+    BitGrid::from_grid(grid, |square| *square == Square::Paper)
 }
 
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = Grid<Square>;
+    type Parsed<'a> = Grid<Square>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         let parse_square = char_match! {
             '.' => Square::Blank,
             '@' => Square::Paper,
@@ -35,23 +31,16 @@ impl Solution for Sol {
         Grid::parser(parse_square)
     }
 
-    fn part1(&self, grid: &Self::Parsed) -> String {
-        get_accessible_paper_positions(grid).count().to_string()
+    fn part1(&self, grid: &Self::Parsed<'_>) -> String {
+        let paper = paper_bits(grid);
+        (paper.count_ones() - paper.step_automaton(stays).count_ones()).to_string()
     }
 
-    fn part2(&self, grid: &Self::Parsed) -> String {
-        let mut grid: Grid<Square> = grid.clone();
-        let mut total_accessible_squares = 0;
-        while let accessible_square_positions =
-            get_accessible_paper_positions(&mut grid).collect::<Vec<GridPosition>>()
-            && accessible_square_positions.len() > 0
-        {
-            for &pos in &accessible_square_positions {
-                grid[pos] = Square::Blank;
-            }
-            total_accessible_squares += accessible_square_positions.len();
-        }
-        total_accessible_squares.to_string()
+    fn part2(&self, grid: &Self::Parsed<'_>) -> String {
+        let paper = paper_bits(grid);
+        let total = paper.count_ones();
+        let (_, remaining) = paper.run_until_stable(stays);
+        (total - remaining.count_ones()).to_string()
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::sections;
 use crate::solutions::Solution;
 use crate::utils::parser;
 use crate::utils::parser::{Parser, StrParser};
@@ -8,15 +9,15 @@ type IdType = u64;
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = (Vec<Range<IdType>>, Vec<IdType>);
+    type Parsed<'a> = (Vec<Range<IdType>>, Vec<IdType>);
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         let range_parser = parser::from_str::<Range<IdType>>.lines();
         let id_parser = parser::from_str::<IdType>.lines();
-        parser::split_pair(range_parser, id_parser, "\n\n")
+        sections!(range_parser, id_parser)
     }
 
-    fn part1(&self, (ranges, ids): &Self::Parsed) -> String {
+    fn part1(&self, (ranges, ids): &Self::Parsed<'_>) -> String {
         let mut fresh_ids = 0;
         for id in ids {
             for range in ranges {
@@ -29,7 +30,7 @@ impl Solution for Sol {
         fresh_ids.to_string()
     }
 
-    fn part2(&self, (ranges, _): &Self::Parsed) -> String {
+    fn part2(&self, (ranges, _): &Self::Parsed<'_>) -> String {
         let mut ranges = ranges.clone();
         ranges.sort_by_key(|range| range.start().clone());
 
@@ -79,4 +80,10 @@ mod tests {
     fn test_part2() {
         check_part2(&Sol, TEST_INPUT, "14");
     }
+
+    #[test]
+    fn test_part1_crlf_trailing_blank_lines() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n\r\n";
+        check_part1(&Sol, &input, "3");
+    }
 }
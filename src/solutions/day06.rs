@@ -23,50 +23,36 @@ fn calculate_sum(operators: &[Operator], num_groups: &[Vec<u64>]) -> u64 {
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = (Vec<String>, Vec<Operator>);
+    type Parsed<'a> = (&'a str, Vec<Operator>);
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         let parse_operator = char_match! {
             '+' => Operator::Add,
             '*' => Operator::Multiply,
         };
-        let num_grid_lines_parser = parser::as_string.lines();
         let operators_parser = parse_operator.single_char().split_whitespace();
-        parser::rsplit_once(num_grid_lines_parser, operators_parser, "\n")
+        parser::rsplit_once(parser::as_str, operators_parser, "\n")
     }
 
-    fn part1(&self, (num_grid_lines, operators): &Self::Parsed) -> String {
+    fn part1(&self, (num_grid, operators): &Self::Parsed<'_>) -> String {
         let num_grid = parser::from_str::<u64>
             .split_whitespace()
-            .into_each()
-            .parse(num_grid_lines.iter().map(String::as_str))
+            .lines()
+            .parse(num_grid)
             .unwrap();
         let num_groups = utils::row_to_column_major(num_grid);
         calculate_sum(&operators, &num_groups).to_string()
     }
 
-    fn part2(&self, (num_grid_lines, operators): &Self::Parsed) -> String {
-        let char_grid = parser::identity
-            .chars()
-            .into_each()
-            .parse(num_grid_lines.iter().map(String::as_str))
+    fn part2(&self, (num_grid, operators): &Self::Parsed<'_>) -> String {
+        // Numbers are written top-to-bottom in columns, with groups separated by blank columns
+        let num_groups = parser::from_str::<u64>
+            .trim()
+            .lines()
+            .transpose_lines()
+            .columns_split_on_blank()
+            .parse(num_grid)
             .unwrap();
-        // Convert grid to column-major format as characters
-        let col_major_char_grid: Vec<Vec<char>> = utils::row_to_column_major(char_grid);
-
-        // Group columns by empty spaces to form number groups
-        let num_groups: Vec<Vec<u64>> = col_major_char_grid
-            .iter()
-            .map(|col| col.iter().collect::<String>().trim().to_string())
-            .batching(|it| {
-                // Take consecutive non-empty strings as one group
-                let nums = it
-                    .take_while(|s| !s.is_empty())
-                    .map(|s| s.parse().unwrap())
-                    .collect_vec();
-                (!nums.is_empty()).then_some(nums)
-            })
-            .collect();
 
         calculate_sum(&operators, &num_groups).to_string()
     }
@@ -1,6 +1,6 @@
 use crate::char_match;
 use crate::solutions::Solution;
-use crate::utils::grid::{Grid, GridPosition};
+use crate::utils::grid::{Direction, Grid, GridPosition};
 use crate::utils::parser::Parser;
 use std::collections::{HashMap, HashSet};
 
@@ -12,70 +12,46 @@ pub enum Square {
     Beam,
 }
 
-fn insert_beam(beams: &mut HashMap<GridPosition, u64>, pos: GridPosition, possibilities: u64) {
-    beams
-        .entry(pos)
-        .and_modify(|p| *p += possibilities)
-        .or_insert(possibilities);
+/// The splitter a beam travelling down from `pos` runs into, if any.
+fn next_splitter(manifold: &Grid<Square>, pos: GridPosition) -> Option<GridPosition> {
+    let (splitter, _) =
+        manifold.first_hit(pos, Direction::Down, |&square| square == Square::Splitter)?;
+    Some(splitter)
 }
 
-fn move_beam_down_part1(
-    manifold: &Grid<Square>,
-    beam_pos: GridPosition,
-    next_beam_pos: &mut HashSet<GridPosition>,
-) -> usize {
-    let below_pos = manifold.below(&beam_pos).unwrap();
-
-    match manifold[below_pos] {
-        Square::Blank => {
-            next_beam_pos.insert(below_pos);
-            0
-        }
-        Square::Splitter => {
-            if let Some(left_pos) = manifold.left(&below_pos) {
-                next_beam_pos.insert(left_pos);
-            }
-            if let Some(right_pos) = manifold.right(&below_pos) {
-                next_beam_pos.insert(right_pos);
-            }
-            1
-        }
-        Square::Beam => panic!("There should not be beams on the grid"),
-        Square::Source => panic!("There should only be one source on the grid"),
-    }
+/// Where the two beams leaving `splitter` start, skipping any that would leave the
+/// manifold.
+fn split(manifold: &Grid<Square>, splitter: GridPosition) -> impl Iterator<Item = GridPosition> {
+    [Direction::Left, Direction::Right]
+        .into_iter()
+        .filter_map(move |dir| manifold.step(splitter, dir))
 }
 
-fn move_beam_down_part2(
+/// Number of timelines a beam starting at `pos` ends up in, memoised by splitter.
+fn count_timelines(
     manifold: &Grid<Square>,
-    beam_pos: GridPosition,
-    possibilities: u64,
-    next_beam_possibilities: &mut HashMap<GridPosition, u64>,
-) {
-    let below_pos = manifold.below(&beam_pos).unwrap();
-
-    match manifold[below_pos] {
-        Square::Blank => {
-            insert_beam(next_beam_possibilities, below_pos, possibilities);
-        }
-        Square::Splitter => {
-            if let Some(left_pos) = manifold.left(&below_pos) {
-                insert_beam(next_beam_possibilities, left_pos, possibilities);
-            }
-            if let Some(right_pos) = manifold.right(&below_pos) {
-                insert_beam(next_beam_possibilities, right_pos, possibilities);
-            }
-        }
-        Square::Beam => panic!("There should not be beams on the grid"),
-        Square::Source => panic!("There should only be one source on the grid"),
+    pos: GridPosition,
+    memo: &mut HashMap<GridPosition, u64>,
+) -> u64 {
+    let Some(splitter) = next_splitter(manifold, pos) else {
+        return 1;
+    };
+    if let Some(&timelines) = memo.get(&splitter) {
+        return timelines;
     }
+    let timelines = split(manifold, splitter)
+        .map(|beam| count_timelines(manifold, beam, memo))
+        .sum();
+    memo.insert(splitter, timelines);
+    timelines
 }
 
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = Grid<Square>;
+    type Parsed<'a> = Grid<Square>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         let parse_square = char_match! {
             '.' => Square::Blank,
             'S' => Square::Source,
@@ -85,33 +61,24 @@ impl Solution for Sol {
         Grid::parser(parse_square)
     }
 
-    fn part1(&self, manifold: &Self::Parsed) -> String {
+    fn part1(&self, manifold: &Self::Parsed<'_>) -> String {
         let source_pos = manifold.find(&Square::Source).unwrap();
 
-        let mut beam_pos = HashSet::from([source_pos]);
-        let mut collisions = 0;
-        for _ in 0..(manifold.height() - 1) {
-            let mut next_beam_pos = HashSet::new();
-            for pos in beam_pos {
-                collisions += move_beam_down_part1(&manifold, pos, &mut next_beam_pos);
+        let mut hit = HashSet::new();
+        let mut beams = vec![source_pos];
+        while let Some(beam) = beams.pop() {
+            if let Some(splitter) = next_splitter(manifold, beam)
+                && hit.insert(splitter)
+            {
+                beams.extend(split(manifold, splitter));
             }
-            beam_pos = next_beam_pos;
         }
-        collisions.to_string()
+        hit.len().to_string()
     }
 
-    fn part2(&self, manifold: &Self::Parsed) -> String {
+    fn part2(&self, manifold: &Self::Parsed<'_>) -> String {
         let source_pos = manifold.find(&Square::Source).unwrap();
-
-        let mut beam_possibilities = HashMap::from([(source_pos, 1)]);
-        for _ in 0..(manifold.height() - 1) {
-            let mut next_beam_possibilities = HashMap::new();
-            for (pos, possibilities) in beam_possibilities {
-                move_beam_down_part2(&manifold, pos, possibilities, &mut next_beam_possibilities);
-            }
-            beam_possibilities = next_beam_possibilities;
-        }
-        beam_possibilities.values().sum::<u64>().to_string()
+        count_timelines(manifold, source_pos, &mut HashMap::new()).to_string()
     }
 }
 
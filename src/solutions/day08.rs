@@ -10,13 +10,13 @@ pub const NUM_CONNECTIONS_PART_1: usize = 1000;
 pub struct Sol<const NUM_CONNECTIONS: usize>;
 
 impl<const NUM_CONNECTIONS: usize> Solution for Sol<NUM_CONNECTIONS> {
-    type Parsed = Vec<Point3<f64>>;
+    type Parsed<'a> = Vec<Point3<f64>>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         parser::from_str::<Point3<f64>>.lines()
     }
 
-    fn part1(&self, points: &Self::Parsed) -> String {
+    fn part1(&self, points: &Self::Parsed<'_>) -> String {
         let pairs = geometry::k_closest_pair_indices(&points, NUM_CONNECTIONS);
         let mut union_find = UnionFind::new(points.len());
         for (left, right) in pairs {
@@ -34,7 +34,7 @@ impl<const NUM_CONNECTIONS: usize> Solution for Sol<NUM_CONNECTIONS> {
             .to_string()
     }
 
-    fn part2(&self, points: &Self::Parsed) -> String {
+    fn part2(&self, points: &Self::Parsed<'_>) -> String {
         let pairs = geometry::closest_pair_indices(points);
         let mut union_find = UnionFind::new(points.len());
 
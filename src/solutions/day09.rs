@@ -72,16 +72,16 @@ fn is_in_loop(x: f64, y: f64, horizontal_walls: &[Wall]) -> bool {
 pub struct Sol;
 
 impl Solution for Sol {
-    type Parsed = Vec<RedTile>;
+    type Parsed<'a> = Vec<RedTile>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         parser::from_str::<u64>
             .split_array(",")
             .map(RedTile::new)
             .lines()
     }
 
-    fn part1(&self, tiles: &Self::Parsed) -> String {
+    fn part1(&self, tiles: &Self::Parsed<'_>) -> String {
         tiles
             .iter()
             .tuple_combinations()
@@ -91,7 +91,7 @@ impl Solution for Sol {
             .to_string()
     }
 
-    fn part2(&self, tiles: &Self::Parsed) -> String {
+    fn part2(&self, tiles: &Self::Parsed<'_>) -> String {
         let (horizontal_walls, vertical_walls): (Vec<_>, _) = tiles
             .iter()
             .circular_tuple_windows()
@@ -59,9 +59,9 @@ fn get_possible_presses_for_indicators(
 }
 
 impl Solution for Sol {
-    type Parsed = Vec<(Vec<bool>, Vec<Vec<usize>>, Vec<usize>)>;
+    type Parsed<'a> = Vec<(Vec<bool>, Vec<Vec<usize>>, Vec<usize>)>;
 
-    fn parser(&self) -> impl Parser<&str, Output = Self::Parsed> {
+    fn parser<'a>(&self) -> impl Parser<&'a str, Output = Self::Parsed<'a>> {
         let indicator = char_match! {
             '.' => false,
             '#' => true,
@@ -78,10 +78,16 @@ impl Solution for Sol {
             " ",
         )
         .map(|(indicators, (schematics, requirements))| (indicators, schematics, requirements))
+        .filter(
+            |(indicators, schematics, _)| {
+                schematics.iter().flatten().all(|&i| i < indicators.len())
+            },
+            "schematic index out of range of indicators",
+        )
         .lines()
     }
 
-    fn part1(&self, machines: &Self::Parsed) -> String {
+    fn part1(&self, machines: &Self::Parsed<'_>) -> String {
         let mut sum_presses = 0;
         for (indicators, schematics, _) in machines {
             sum_presses += get_possible_presses_for_indicators(&schematics, &indicators)
@@ -92,7 +98,7 @@ impl Solution for Sol {
         sum_presses.to_string()
     }
 
-    fn part2(&self, machines: &Self::Parsed) -> String {
+    fn part2(&self, machines: &Self::Parsed<'_>) -> String {
         todo!()
     }
 }
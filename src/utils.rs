@@ -2,7 +2,10 @@ pub mod algebra;
 pub mod geometry;
 pub mod grid;
 pub mod parser;
+#[cfg(test)]
+pub mod property;
 pub mod range;
+pub mod search;
 pub mod union_find;
 
 pub fn digits_to_num(digits: &[u32]) -> u64 {
@@ -6,7 +6,7 @@ use itertools::Itertools;
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Vector<T, const N: usize> {
     vals: [T; N],
 }
@@ -21,6 +21,12 @@ impl<T, const N: usize> Vector<T, N> {
     }
 }
 
+impl<T: Display, const N: usize> Display for Vector<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.vals.iter().join(","))
+    }
+}
+
 impl<T, const N: usize> FromStr for Vector<T, N>
 where
     T: FromStr,
@@ -98,3 +104,18 @@ pub fn closest_pair_indices<const N: usize>(
         .sorted_by(|(_, dist1), (_, dist2)| dist1.total_cmp(dist2))
         .map(|(pair, _)| pair)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::property::{self, arrays, ints};
+
+    #[test]
+    fn test_round_trip() {
+        property::round_trip(
+            &arrays::<_, 3>(ints(i64::MIN..=i64::MAX)),
+            |&vals| Vector::new(vals).to_string(),
+            parser::from_str::<Vector3<i64>>.map(|v| v.vals),
+        );
+    }
+}
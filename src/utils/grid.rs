@@ -1,6 +1,23 @@
 #![allow(dead_code)]
 
-use crate::utils::parser::{CharParser, ParseError, Parser, StrParser};
+mod automaton;
+mod bits;
+mod ray;
+mod region;
+mod render;
+mod sparse;
+mod topology;
+mod transform;
+
+pub use bits::BitGrid;
+pub use ray::line;
+pub use region::{Connectivity, Region};
+pub use sparse::SparseGrid;
+pub use topology::{Bounded, Clamped, Topology, TopologyView, Wrapping};
+pub use transform::SubGrid;
+
+use crate::utils::parser::{self, ByteParser, CharParser, ParseError, Parser};
+use crate::utils::search::{Cost, SearchResult};
 use std::ops::{Index, IndexMut};
 
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
@@ -9,9 +26,80 @@ pub struct GridPosition {
     pub y: usize,
 }
 
-#[derive(Clone)]
+/// One of the eight compass directions on a grid, where "up" is decreasing `y`.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from `Up`.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All eight directions, clockwise from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The `(dx, dy)` offset of a single step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Grid<T> {
-    cells: Vec<Vec<T>>,
+    cells: Vec<T>,
     height: usize,
     width: usize,
 }
@@ -22,16 +110,38 @@ impl<T> Grid<T> {
         I: IntoIterator<Item = J>,
         J: IntoIterator<Item = T>,
     {
-        let cells: Vec<Vec<T>> = rows
-            .into_iter()
-            .map(|row| row.into_iter().collect())
-            .collect();
+        Self::try_from_rows(rows.into_iter().map(|row| row.into_iter().map(Ok)))
+    }
+
+    fn try_from_rows<I, J>(rows: I) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = J>,
+        J: IntoIterator<Item = Result<T, ParseError>>,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (y, row) in rows.into_iter().enumerate() {
+            let row_start = cells.len();
+            for cell in row {
+                cells.push(cell?);
+            }
+            let row_width = cells.len() - row_start;
+            if y == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(ParseError::Ragged {
+                    row: y + 1,
+                    expected: width,
+                    got: row_width,
+                });
+            }
+            height += 1;
+        }
 
-        let height = cells.len();
         if height == 0 {
             return Err("Grid cannot have height 0".to_string().into());
         }
-        let width = cells.first().unwrap().len();
         if width == 0 {
             return Err("Grid cannot have width 0".to_string().into());
         }
@@ -46,7 +156,23 @@ impl<T> Grid<T> {
     pub fn parser(
         cell: impl CharParser<Output = T>,
     ) -> impl for<'a> Parser<&'a str, Output = Grid<T>> {
-        cell.chars().lines().and_then(Grid::from_rows)
+        move |input: &str| {
+            Grid::try_from_rows(
+                input
+                    .lines()
+                    .map(|line| line.chars().map(|c| cell.parse(c))),
+            )
+        }
+    }
+
+    pub fn parser_bytes(
+        cell: impl ByteParser<Output = T>,
+    ) -> impl for<'a> Parser<&'a [u8], Output = Grid<T>> {
+        move |input: &[u8]| {
+            Grid::try_from_rows(
+                parser::byte_lines(input).map(|line| line.iter().map(|&b| cell.parse(b))),
+            )
+        }
     }
 
     pub fn height(&self) -> usize {
@@ -57,85 +183,85 @@ impl<T> Grid<T> {
         self.width
     }
 
-    pub fn below(&self, pos: &GridPosition) -> Option<GridPosition> {
-        let new_y = pos.y + 1;
-        if new_y >= self.height {
-            return None;
-        }
-        Some(GridPosition { x: pos.x, y: new_y })
+    fn index_of(&self, pos: GridPosition) -> usize {
+        pos.y * self.width + pos.x
     }
 
-    pub fn above(&self, pos: &GridPosition) -> Option<GridPosition> {
-        if pos.y == 0 {
-            return None;
+    fn position_of(&self, i: usize) -> GridPosition {
+        GridPosition {
+            x: i % self.width,
+            y: i / self.width,
         }
-        Some(GridPosition {
-            x: pos.x,
-            y: pos.y - 1,
-        })
     }
 
-    pub fn right(&self, pos: &GridPosition) -> Option<GridPosition> {
-        let new_x = pos.x + 1;
-        if new_x >= self.width {
-            return None;
-        }
-        Some(GridPosition { x: new_x, y: pos.y })
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
     }
 
-    pub fn left(&self, pos: &GridPosition) -> Option<GridPosition> {
-        if pos.x == 0 {
-            return None;
-        }
-        Some(GridPosition {
-            x: pos.x - 1,
-            y: pos.y,
-        })
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
     }
 
     pub fn iter_enumerated(&self) -> impl Iterator<Item = (GridPosition, &T)> {
-        self.cells.iter().enumerate().flat_map(|(y, row)| {
+        self.rows().enumerate().flat_map(|(y, row)| {
             row.iter()
                 .enumerate()
                 .map(move |(x, cell)| (GridPosition { x, y }, cell))
         })
     }
 
-    pub fn surrounding_cells(&self, pos: GridPosition) -> impl Iterator<Item = &T> {
-        const OFFSETS: [(isize, isize); 8] = [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ];
-
-        OFFSETS.iter().filter_map(move |&(r_off, c_off)| {
-            let new_x = pos.x.checked_add_signed(c_off)?;
-            let new_y = pos.y.checked_add_signed(r_off)?;
-
-            if new_x < self.width && new_y < self.height {
-                Some(&self.cells[new_y][new_x])
-            } else {
-                None
-            }
-        })
+    pub fn step(&self, pos: GridPosition, dir: Direction) -> Option<GridPosition> {
+        self.with_topology(Bounded).step(pos, dir)
+    }
+
+    pub fn neighbors4(&self, pos: GridPosition) -> impl Iterator<Item = (GridPosition, &T)> {
+        topology::neighbors_in(self.with_topology(Bounded), pos, &Direction::CARDINAL)
+    }
+
+    pub fn neighbors8(&self, pos: GridPosition) -> impl Iterator<Item = (GridPosition, &T)> {
+        topology::neighbors_in(self.with_topology(Bounded), pos, &Direction::ALL)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Like [`TopologyView::bfs_from`] using [`Bounded`].
+    pub fn bfs_from(
+        &self,
+        start: GridPosition,
+        passable: impl Fn(&T) -> bool,
+    ) -> SearchResult<GridPosition, usize> {
+        self.with_topology(Bounded).bfs_from(start, passable)
+    }
+
+    /// Like [`TopologyView::dijkstra_from`] using [`Bounded`].
+    pub fn dijkstra_from<C: Cost>(
+        &self,
+        start: GridPosition,
+        cost: impl Fn(&T) -> Option<C>,
+    ) -> SearchResult<GridPosition, C> {
+        self.with_topology(Bounded).dijkstra_from(start, cost)
+    }
+
+    /// Like [`TopologyView::shortest_path`] using [`Bounded`].
+    pub fn shortest_path(
+        &self,
+        start: GridPosition,
+        goal: GridPosition,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Vec<GridPosition>> {
+        self.with_topology(Bounded)
+            .shortest_path(start, goal, passable)
     }
 }
 
 impl<T: PartialEq> Grid<T> {
     pub fn find(&self, elem: &T) -> Option<GridPosition> {
-        for (y, row) in self.cells.iter().enumerate() {
-            for (x, cell) in row.iter().enumerate() {
-                if elem == cell {
-                    return Some(GridPosition { x, y });
-                }
-            }
-        }
-        None
+        let i = self.cells.iter().position(|cell| cell == elem)?;
+        Some(self.position_of(i))
     }
 }
 
@@ -143,12 +269,111 @@ impl<T> Index<GridPosition> for Grid<T> {
     type Output = T;
 
     fn index(&self, pos: GridPosition) -> &Self::Output {
-        &self.cells[pos.y][pos.x]
+        assert!(pos.x < self.width, "x out of bounds: {pos:?}");
+        &self.cells[self.index_of(pos)]
     }
 }
 
 impl<T> IndexMut<GridPosition> for Grid<T> {
     fn index_mut(&mut self, pos: GridPosition) -> &mut Self::Output {
-        &mut self.cells[pos.y][pos.x]
+        assert!(pos.x < self.width, "x out of bounds: {pos:?}");
+        let i = self.index_of(pos);
+        &mut self.cells[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parser;
+    use crate::utils::property::{self, one_of, rectangles};
+    use itertools::Itertools;
+
+    /// Parses a grid of characters, for tests across the grid modules.
+    pub(super) fn grid(input: &str) -> Grid<char> {
+        Grid::parser(parser::identity).parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        property::round_trip(
+            &rectangles(one_of(&['.', '#', 'S']), 12),
+            |rows| {
+                rows.iter()
+                    .map(|row| row.iter().collect::<String>())
+                    .join("\n")
+            },
+            Grid::parser(parser::identity).map(|grid| grid.rows().map(<[_]>::to_vec).collect()),
+        );
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.opposite().opposite(), dir);
+            let (dx, dy) = dir.offset();
+            assert_eq!(dir.opposite().offset(), (-dx, -dy));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::UpLeft.turn_left(), Direction::DownLeft);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid("abc\ndef\nghi");
+        let corner = GridPosition { x: 0, y: 0 };
+        let centre = GridPosition { x: 1, y: 1 };
+
+        let cells = |it: &mut dyn Iterator<Item = (GridPosition, &char)>| {
+            it.map(|(_, &c)| c).collect::<String>()
+        };
+        assert_eq!(cells(&mut grid.neighbors4(corner)), "bd");
+        assert_eq!(cells(&mut grid.neighbors8(corner)), "bed");
+        assert_eq!(cells(&mut grid.neighbors4(centre)), "bfhd");
+        assert_eq!(cells(&mut grid.neighbors8(centre)), "bcfihgda");
+        assert_eq!(grid.step(corner, Direction::Left), None);
+        assert_eq!(grid.step(corner, Direction::DownRight), Some(centre));
+    }
+
+    #[test]
+    fn test_search() {
+        let maze = grid("S.#.\n#...\n..#G\n.##.");
+        let start = maze.find(&'S').unwrap();
+        let goal = maze.find(&'G').unwrap();
+
+        let result = maze.bfs_from(start, |&c| c != '#');
+        assert_eq!(result.distance(&goal), Some(5));
+        assert_eq!(result.distance(&GridPosition { x: 0, y: 3 }), Some(5));
+        assert_eq!(result.path_to(&goal).unwrap().len(), 6);
+
+        let path = maze.shortest_path(start, goal, |&c| c != '#').unwrap();
+        assert_eq!(path.len(), 6);
+        assert!(path.windows(2).all(|w| {
+            w[0].x.abs_diff(w[1].x) + w[0].y.abs_diff(w[1].y) == 1 && maze[w[1]] != '#'
+        }));
+
+        let weighted = maze.dijkstra_from(start, |&c| {
+            (c != '#').then_some(if c == '.' { 1 } else { 0 })
+        });
+        assert_eq!(weighted.distance(&goal), Some(4));
+        assert_eq!(
+            maze.shortest_path(start, GridPosition { x: 2, y: 0 }, |&c| c != '#'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rejects_ragged_rows() {
+        let grid = Grid::parser(parser::identity).parse("..#\n.#.\n#.\n...");
+        assert_eq!(
+            grid,
+            Err(ParseError::Ragged {
+                row: 3,
+                expected: 3,
+                got: 2
+            })
+        );
     }
 }
@@ -0,0 +1,141 @@
+use super::Grid;
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Computes the next generation of a cellular automaton, where each cell becomes
+    /// `rule(cell, n)` and `n` is the number of its 8 neighbours matching `counted`.
+    pub fn step_automaton(
+        &self,
+        counted: impl Fn(&T) -> bool,
+        rule: impl Fn(&T, usize) -> T,
+    ) -> Grid<T> {
+        let mut next = self.clone();
+        self.step_automaton_into(&mut next, counted, rule);
+        next
+    }
+
+    /// Like [`Grid::step_automaton`], but writes the next generation into `next`
+    /// so that buffers can be reused. Returns how many cells changed.
+    pub fn step_automaton_into(
+        &self,
+        next: &mut Grid<T>,
+        counted: impl Fn(&T) -> bool,
+        rule: impl Fn(&T, usize) -> T,
+    ) -> usize {
+        assert_eq!(
+            (self.width, self.height),
+            (next.width, next.height),
+            "automaton buffers must have the same dimensions"
+        );
+        let mut changed = 0;
+        for ((pos, cell), slot) in self.iter_enumerated().zip(&mut next.cells) {
+            let count = self.neighbors8(pos).filter(|(_, n)| counted(n)).count();
+            *slot = rule(cell, count);
+            changed += usize::from(slot != cell);
+        }
+        changed
+    }
+
+    /// Steps the automaton until a generation changes nothing, returning how many
+    /// generations changed the grid and the final grid.
+    pub fn run_until_stable(
+        self,
+        counted: impl Fn(&T) -> bool,
+        rule: impl Fn(&T, usize) -> T,
+    ) -> (usize, Grid<T>) {
+        let mut current = self;
+        let mut next = current.clone();
+        let mut generations = 0;
+        while current.step_automaton_into(&mut next, &counted, &rule) > 0 {
+            std::mem::swap(&mut current, &mut next);
+            generations += 1;
+        }
+        (generations, current)
+    }
+
+    /// Same result as [`Grid::run_until_stable`], but after the first generation
+    /// only re-examines cells next to one that changed. Much faster when activity
+    /// dies out locally.
+    pub fn run_until_stable_frontier(
+        self,
+        counted: impl Fn(&T) -> bool,
+        rule: impl Fn(&T, usize) -> T,
+    ) -> (usize, Grid<T>) {
+        let mut grid = self;
+        let mut frontier: Vec<usize> = (0..grid.cells.len()).collect();
+        let mut queued = vec![false; grid.cells.len()];
+        let mut generations = 0;
+        loop {
+            let changes: Vec<(usize, T)> = frontier
+                .drain(..)
+                .filter_map(|i| {
+                    let pos = grid.position_of(i);
+                    let count = grid.neighbors8(pos).filter(|(_, n)| counted(n)).count();
+                    let cell = &grid.cells[i];
+                    let updated = rule(cell, count);
+                    (updated != *cell).then_some((i, updated))
+                })
+                .collect();
+            if changes.is_empty() {
+                return (generations, grid);
+            }
+            generations += 1;
+
+            queued.fill(false);
+            for (i, cell) in changes {
+                grid.cells[i] = cell;
+                let pos = grid.position_of(i);
+                let around = grid.neighbors8(pos).map(|(n, _)| grid.index_of(n));
+                for j in std::iter::once(i).chain(around) {
+                    if !queued[j] {
+                        queued[j] = true;
+                        frontier.push(j);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::tests::grid;
+    use crate::utils::property::{self, one_of, rectangles};
+
+    fn life(cell: &char, alive: usize) -> char {
+        match (cell, alive) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    fn erode(cell: &char, walls: usize) -> char {
+        if *cell == '#' && walls < 4 {
+            '.'
+        } else {
+            *cell
+        }
+    }
+
+    #[test]
+    fn test_blinker() {
+        let blinker = grid(".....\n..#..\n..#..\n..#..\n.....");
+        let flipped = blinker.step_automaton(|&c| c == '#', life);
+        assert_eq!(flipped.row(2), ['.', '#', '#', '#', '.']);
+        assert_eq!(flipped.step_automaton(|&c| c == '#', life), blinker);
+    }
+
+    #[test]
+    fn test_frontier_matches_full_run() {
+        property::forall(&rectangles(one_of(&['.', '#']), 12), |rows| {
+            let grid = Grid::from_rows(rows.clone()).unwrap();
+            let full = grid.clone().run_until_stable(|&c| c == '#', erode);
+            let frontier = grid.run_until_stable_frontier(|&c| c == '#', erode);
+            if full == frontier {
+                Ok(())
+            } else {
+                Err(format!("{full:?} != {frontier:?}"))
+            }
+        });
+    }
+}
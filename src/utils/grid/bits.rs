@@ -0,0 +1,295 @@
+use super::{Grid, GridPosition};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A two-state grid packing each row into `u64` words, one bit per cell.
+///
+/// Bits past `width` in each row's last word are always zero.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+}
+
+impl BitGrid {
+    /// An all-clear grid.
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        Self {
+            words: vec![0; words_per_row * height],
+            width,
+            height,
+            words_per_row,
+        }
+    }
+
+    /// Sets exactly the cells of `grid` for which `pred` holds.
+    pub fn from_grid<T>(grid: &Grid<T>, pred: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.width, grid.height);
+        for (row, words) in grid
+            .rows()
+            .zip(bits.words.chunks_exact_mut(bits.words_per_row))
+        {
+            for (x, cell) in row.iter().enumerate() {
+                words[x / WORD_BITS] |= u64::from(pred(cell)) << (x % WORD_BITS);
+            }
+        }
+        bits
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid {
+            cells: (0..self.height)
+                .flat_map(|y| (0..self.width).map(move |x| self.get(GridPosition { x, y })))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn locate(&self, pos: GridPosition) -> (usize, u64) {
+        assert!(
+            pos.x < self.width && pos.y < self.height,
+            "position out of bounds: {pos:?}"
+        );
+        let word = pos.y * self.words_per_row + pos.x / WORD_BITS;
+        (word, 1 << (pos.x % WORD_BITS))
+    }
+
+    pub fn get(&self, pos: GridPosition) -> bool {
+        let (word, bit) = self.locate(pos);
+        self.words[word] & bit != 0
+    }
+
+    pub fn set(&mut self, pos: GridPosition, value: bool) {
+        let (word, bit) = self.locate(pos);
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+    }
+
+    /// Number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Positions of the set cells, in row-major order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = GridPosition> + '_ {
+        (0..self.height).flat_map(move |y| {
+            self.row(y).iter().enumerate().flat_map(move |(i, &word)| {
+                let mut rest = word;
+                std::iter::from_fn(move || {
+                    (rest != 0).then(|| {
+                        let bit = rest.trailing_zeros() as usize;
+                        rest &= rest - 1;
+                        GridPosition {
+                            x: i * WORD_BITS + bit,
+                            y,
+                        }
+                    })
+                })
+            })
+        })
+    }
+
+    /// Mask of the valid bits in a row's last word.
+    fn tail_mask(&self) -> u64 {
+        match self.width % WORD_BITS {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// Computes the next generation of a cellular automaton where each cell becomes
+    /// `rule(set, n)` and `n` is the number of its set 8-neighbours. Works a whole
+    /// word of cells at a time.
+    pub fn step_automaton(&self, rule: impl Fn(bool, usize) -> bool) -> BitGrid {
+        let mut next = BitGrid::new(self.width, self.height);
+        self.step_automaton_into(&mut next, rule);
+        next
+    }
+
+    /// Like [`BitGrid::step_automaton`], but writes the next generation into `next`
+    /// so that buffers can be reused. Returns how many cells changed.
+    pub fn step_automaton_into(
+        &self,
+        next: &mut BitGrid,
+        rule: impl Fn(bool, usize) -> bool,
+    ) -> usize {
+        assert_eq!(
+            (self.width, self.height),
+            (next.width, next.height),
+            "automaton buffers must have the same dimensions"
+        );
+        // Bit n is set if a cell with n set neighbours becomes set.
+        let counts_for = |set| {
+            (0..=8)
+                .filter(|&n| rule(set, n))
+                .fold(0u16, |acc, n| acc | 1 << n)
+        };
+        let (births, survivals) = (counts_for(false), counts_for(true));
+        let tail_mask = self.tail_mask();
+
+        let mut changed = 0;
+        for y in 0..self.height {
+            let above = if y > 0 { self.row(y - 1) } else { &[] };
+            let centre = self.row(y);
+            let below = if y + 1 < self.height {
+                self.row(y + 1)
+            } else {
+                &[]
+            };
+            let out = &mut next.words[y * self.words_per_row..(y + 1) * self.words_per_row];
+            for (i, word) in out.iter_mut().enumerate() {
+                let planes = neighbour_count_planes([above, centre, below], i);
+                let current = centre[i];
+                *word = (current & count_mask(&planes, survivals))
+                    | (!current & count_mask(&planes, births));
+            }
+            if let Some(last) = out.last_mut() {
+                *last &= tail_mask;
+            }
+            changed += centre
+                .iter()
+                .zip(out.iter())
+                .map(|(old, new)| (old ^ new).count_ones() as usize)
+                .sum::<usize>();
+        }
+        changed
+    }
+
+    /// Steps the automaton until a generation changes nothing, returning how many
+    /// generations changed the grid and the final grid. Alternates between two
+    /// buffers rather than allocating each generation.
+    pub fn run_until_stable(self, rule: impl Fn(bool, usize) -> bool) -> (usize, BitGrid) {
+        let mut current = self;
+        let mut next = BitGrid::new(current.width, current.height);
+        let mut generations = 0;
+        while current.step_automaton_into(&mut next, &rule) > 0 {
+            std::mem::swap(&mut current, &mut next);
+            generations += 1;
+        }
+        (generations, current)
+    }
+}
+
+/// Counts the set 8-neighbours of the cells in word `i` of the middle row,
+/// bit-sliced: bit `b` of plane `k` is bit `k` of the count for cell `i * 64 + b`.
+/// Rows beyond the grid's edge are passed as empty slices.
+fn neighbour_count_planes(rows: [&[u64]; 3], i: usize) -> [u64; 4] {
+    let mut planes = [0u64; 4];
+    let mut add = |bits: u64| {
+        let mut carry = bits;
+        for plane in &mut planes {
+            let sum = *plane ^ carry;
+            carry &= *plane;
+            *plane = sum;
+        }
+    };
+    for (r, row) in rows.into_iter().enumerate() {
+        let word = |j: usize| row.get(j).copied().unwrap_or(0);
+        let here = word(i);
+        let prev = i.checked_sub(1).map_or(0, word);
+        let next = word(i + 1);
+        // The left neighbour of bit b is bit b - 1, so shift it up onto b.
+        add((here << 1) | (prev >> (WORD_BITS - 1)));
+        add((here >> 1) | (next << (WORD_BITS - 1)));
+        if r != 1 {
+            add(here);
+        }
+    }
+    planes
+}
+
+/// Mask of the cells whose bit-sliced count is one of those set in `counts`.
+fn count_mask(planes: &[u64; 4], counts: u16) -> u64 {
+    (0..=8)
+        .filter(|n| counts >> n & 1 == 1)
+        .map(|n| {
+            (0..4).fold(u64::MAX, |eq, k| {
+                eq & if n >> k & 1 == 1 {
+                    planes[k]
+                } else {
+                    !planes[k]
+                }
+            })
+        })
+        .fold(0, |acc, eq| acc | eq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::property::{self, one_of, rectangles};
+
+    fn erode(set: bool, neighbours: usize) -> bool {
+        set && neighbours >= 4
+    }
+
+    #[test]
+    fn test_bits() {
+        let mut bits = BitGrid::new(130, 2);
+        let far = GridPosition { x: 129, y: 1 };
+        bits.set(far, true);
+        bits.set(GridPosition { x: 63, y: 0 }, true);
+        bits.set(GridPosition { x: 64, y: 0 }, true);
+        assert!(bits.get(far));
+        assert_eq!(bits.count_ones(), 3);
+        assert_eq!(
+            bits.iter_ones().collect::<Vec<_>>(),
+            [
+                GridPosition { x: 63, y: 0 },
+                GridPosition { x: 64, y: 0 },
+                far
+            ]
+        );
+        bits.set(far, false);
+        assert_eq!(bits.count_ones(), 2);
+    }
+
+    #[test]
+    fn test_matches_grid_automaton() {
+        property::forall(&rectangles(one_of(&[false, true]), 70), |rows| {
+            let grid = Grid::from_rows(rows.clone()).unwrap();
+            let bits = BitGrid::from_grid(&grid, |&b| b);
+            let life = |set: bool, n: usize| n == 3 || (set && n == 2);
+            let expected = [
+                grid.step_automaton(|&b| b, |&b, n| life(b, n)),
+                grid.clone()
+                    .run_until_stable_frontier(|&b| b, |&b, n| erode(b, n))
+                    .1,
+            ];
+            let actual = [
+                bits.step_automaton(life).to_grid(),
+                bits.clone().run_until_stable(erode).1.to_grid(),
+            ];
+            let changed = (
+                grid.step_automaton_into(&mut grid.clone(), |&b| b, |&b, n| life(b, n)),
+                bits.step_automaton_into(&mut bits.clone(), life),
+            );
+            if expected != actual {
+                Err(format!("{expected:?} != {actual:?}"))
+            } else if changed.0 != changed.1 {
+                Err(format!("changed {} cells, not {}", changed.1, changed.0))
+            } else {
+                Ok(())
+            }
+        });
+    }
+}
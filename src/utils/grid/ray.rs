@@ -0,0 +1,142 @@
+use super::{Direction, Grid, GridPosition};
+
+impl<T> Grid<T> {
+    /// The cells met walking from `pos` in `dir`, excluding `pos` itself, up to the
+    /// edge of the grid.
+    pub fn ray(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+    ) -> impl Iterator<Item = (GridPosition, &T)> {
+        std::iter::successors(self.step(pos, dir), move |&next| self.step(next, dir))
+            .map(|next| (next, &self[next]))
+    }
+
+    /// The first cell along [`Grid::ray`] matching `pred`.
+    pub fn first_hit(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+        pred: impl Fn(&T) -> bool,
+    ) -> Option<(GridPosition, &T)> {
+        self.ray(pos, dir).find(|(_, cell)| pred(cell))
+    }
+
+    /// The first cell matching `pred` in each of the 8 directions from `pos`,
+    /// skipping directions with no match.
+    pub fn visible_from(
+        &self,
+        pos: GridPosition,
+        pred: impl Fn(&T) -> bool,
+    ) -> impl Iterator<Item = (Direction, GridPosition, &T)> {
+        Direction::ALL.into_iter().filter_map(move |dir| {
+            let (hit, cell) = self.first_hit(pos, dir, &pred)?;
+            Some((dir, hit, cell))
+        })
+    }
+
+    /// Whether no cell strictly between `from` and `to` on their [`line`] is
+    /// `opaque`.
+    pub fn line_of_sight(
+        &self,
+        from: GridPosition,
+        to: GridPosition,
+        opaque: impl Fn(&T) -> bool,
+    ) -> bool {
+        line(from, to)
+            .filter(|&pos| pos != from && pos != to)
+            .all(|pos| !opaque(&self[pos]))
+    }
+}
+
+/// The cells on the Bresenham line from `from` to `to`, including both ends.
+pub fn line(from: GridPosition, to: GridPosition) -> impl Iterator<Item = GridPosition> {
+    let (x1, y1) = (to.x as isize, to.y as isize);
+    let (dx, dy) = ((x1 - from.x as isize).abs(), -(y1 - from.y as isize).abs());
+    let (sx, sy) = (
+        (x1 - from.x as isize).signum(),
+        (y1 - from.y as isize).signum(),
+    );
+    let mut err = dx + dy;
+    let mut next = Some((from.x as isize, from.y as isize));
+    std::iter::from_fn(move || {
+        let (x, y) = next?;
+        next = ((x, y) != (x1, y1)).then(|| {
+            let e2 = 2 * err;
+            let (mut x, mut y) = (x, y);
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+            (x, y)
+        });
+        Some(GridPosition {
+            x: x as usize,
+            y: y as usize,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::tests::grid;
+
+    fn pos(x: usize, y: usize) -> GridPosition {
+        GridPosition { x, y }
+    }
+
+    #[test]
+    fn test_rays() {
+        let room = grid("#...#\n.....\n..@.#\n.....\n#.#..");
+        let centre = room.find(&'@').unwrap();
+        assert_eq!(room.ray(centre, Direction::Up).count(), 2);
+        assert_eq!(
+            room.first_hit(centre, Direction::Right, |&c| c == '#'),
+            Some((pos(4, 2), &'#'))
+        );
+        assert_eq!(room.first_hit(centre, Direction::Left, |&c| c == '#'), None);
+
+        let seen: Vec<_> = room
+            .visible_from(centre, |&c| c == '#')
+            .map(|(dir, hit, _)| (dir, hit))
+            .collect();
+        assert_eq!(
+            seen,
+            [
+                (Direction::UpRight, pos(4, 0)),
+                (Direction::Right, pos(4, 2)),
+                (Direction::Down, pos(2, 4)),
+                (Direction::DownLeft, pos(0, 4)),
+                (Direction::UpLeft, pos(0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line() {
+        let cells = |from, to| line(from, to).map(|p| (p.x, p.y)).collect::<Vec<_>>();
+        assert_eq!(
+            cells(pos(0, 0), pos(4, 2)),
+            [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+        assert_eq!(
+            cells(pos(2, 3), pos(2, 0)),
+            [(2, 3), (2, 2), (2, 1), (2, 0)]
+        );
+        assert_eq!(cells(pos(1, 1), pos(1, 1)), [(1, 1)]);
+        for (a, b) in [(pos(0, 5), pos(7, 1)), (pos(6, 0), pos(0, 7))] {
+            let forward = cells(a, b);
+            assert_eq!(forward.len(), 8);
+            assert_eq!((forward[0], forward[7]), ((a.x, a.y), (b.x, b.y)));
+        }
+
+        let wall = grid("...\n.#.\n...");
+        assert!(!wall.line_of_sight(pos(0, 0), pos(2, 2), |&c| c == '#'));
+        assert!(wall.line_of_sight(pos(0, 1), pos(2, 0), |&c| c == '#'));
+    }
+}
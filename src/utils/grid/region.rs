@@ -0,0 +1,174 @@
+use super::{Bounded, Direction, Grid, GridPosition};
+use crate::utils::union_find::UnionFind;
+use std::collections::{HashMap, HashSet};
+
+/// Which neighbours count as adjacent when joining cells into regions.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Connectivity::Four => &Direction::CARDINAL,
+            Connectivity::Eight => &Direction::ALL,
+        }
+    }
+}
+
+/// A connected component of equal cells, as found by [`Grid::regions`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Region {
+    /// Index of this region in the `Vec` returned by [`Grid::regions`].
+    pub label: usize,
+    /// The region's cells, in row-major order.
+    pub cells: Vec<GridPosition>,
+    /// Number of unit cell edges between the region and anything outside it.
+    pub perimeter: usize,
+    /// Number of corners on the region's boundary, which equals its number of
+    /// straight sides.
+    pub sides: usize,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn corners(&self) -> usize {
+        self.sides
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Splits the grid into maximal connected regions of equal cells, labelled in
+    /// row-major order of their first cell.
+    pub fn regions(&self, connectivity: Connectivity) -> Vec<Region> {
+        let mut components = UnionFind::new(self.cells.len());
+        for (pos, cell) in self.iter_enumerated() {
+            for &dir in connectivity.directions() {
+                if let Some(next) = self.step(pos, dir)
+                    && self[next] == *cell
+                {
+                    components.union(self.index_of(pos), self.index_of(next));
+                }
+            }
+        }
+
+        let mut labels = vec![0; self.cells.len()];
+        let mut label_of_root = HashMap::new();
+        let mut regions: Vec<Region> = Vec::new();
+        for (pos, _) in self.iter_enumerated() {
+            let i = self.index_of(pos);
+            let root = components.find(i);
+            let label = *label_of_root.entry(root).or_insert_with(|| {
+                regions.push(Region {
+                    label: regions.len(),
+                    cells: Vec::new(),
+                    perimeter: 0,
+                    sides: 0,
+                });
+                regions.len() - 1
+            });
+            labels[i] = label;
+            regions[label].cells.push(pos);
+        }
+
+        let same_region = |pos: GridPosition, dir: Direction| {
+            self.step(pos, dir)
+                .is_some_and(|next| labels[self.index_of(next)] == labels[self.index_of(pos)])
+        };
+        for region in &mut regions {
+            for &pos in &region.cells {
+                for dir in Direction::CARDINAL {
+                    let side = same_region(pos, dir);
+                    let next_side = same_region(pos, dir.turn_right());
+                    let diagonal = same_region(pos, dir.rotate(1));
+                    region.perimeter += usize::from(!side);
+                    // Outer corner if both sides leave the region; inner corner if
+                    // both stay in it but the cell between them does not.
+                    region.sides += usize::from(side == next_side && !(side && diagonal));
+                }
+            }
+        }
+        regions
+    }
+}
+
+impl<T> Grid<T> {
+    /// Like [`TopologyView::flood_fill`](super::TopologyView::flood_fill) using
+    /// [`Bounded`].
+    pub fn flood_fill(
+        &self,
+        start: GridPosition,
+        pred: impl Fn(&T) -> bool,
+    ) -> HashSet<GridPosition> {
+        self.with_topology(Bounded).flood_fill(start, pred)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::tests::grid;
+
+    fn price(input: &str, measure: fn(&Region) -> usize) -> usize {
+        grid(input)
+            .regions(Connectivity::Four)
+            .iter()
+            .map(|region| region.area() * measure(region))
+            .sum()
+    }
+
+    #[test]
+    fn test_regions() {
+        let garden = grid("AAAA\nBBCD\nBBCC\nEEEC");
+        let regions = garden.regions(Connectivity::Four);
+        let summary: Vec<_> = regions
+            .iter()
+            .map(|r| (garden[r.cells[0]], r.area(), r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_region_sides_with_holes() {
+        assert_eq!(
+            price("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO", |r| r.perimeter),
+            772
+        );
+        assert_eq!(price("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE", |r| r.sides), 236);
+        let nested = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA";
+        assert_eq!(price(nested, |r| r.sides), 368);
+    }
+
+    #[test]
+    fn test_connectivity() {
+        let checkers = grid("#.\n.#");
+        assert_eq!(checkers.regions(Connectivity::Four).len(), 4);
+        assert_eq!(checkers.regions(Connectivity::Eight).len(), 2);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let maze = grid("..#\n#.#\n..#");
+        let filled = maze.flood_fill(GridPosition { x: 0, y: 0 }, |&c| c == '.');
+        assert_eq!(filled.len(), 5);
+        assert!(!filled.contains(&GridPosition { x: 0, y: 1 }));
+        assert!(
+            maze.flood_fill(GridPosition { x: 2, y: 0 }, |&c| c == '.')
+                .is_empty()
+        );
+    }
+}
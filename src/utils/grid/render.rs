@@ -0,0 +1,75 @@
+use super::{Grid, GridPosition};
+use std::fmt::{self, Display};
+
+impl<T> Grid<T> {
+    /// Draws the grid one row per line, mapping each cell to a character.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.render_overlay(cell, [])
+    }
+
+    /// Like [`Grid::render`], but draws each `(position, char)` of `overlay` on top
+    /// of the cell at that position. Positions outside the grid are ignored.
+    pub fn render_overlay(
+        &self,
+        cell: impl Fn(&T) -> char,
+        overlay: impl IntoIterator<Item = (GridPosition, char)>,
+    ) -> String {
+        let mut canvas: Vec<char> = self.cells.iter().map(cell).collect();
+        for (pos, c) in overlay {
+            if pos.x < self.width && pos.y < self.height {
+                canvas[self.index_of(pos)] = c;
+            }
+        }
+        let mut out = String::with_capacity(canvas.len() + self.height);
+        for (y, row) in canvas.chunks_exact(self.width).enumerate() {
+            if y > 0 {
+                out.push('\n');
+            }
+            out.extend(row);
+        }
+        out
+    }
+}
+
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&cell| cell.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::tests::grid;
+    use crate::utils::parser::Parser;
+    use crate::{char_match, char_render};
+
+    #[derive(PartialEq, Copy, Clone, Debug)]
+    enum Square {
+        Blank,
+        Splitter,
+    }
+
+    #[test]
+    fn test_render_round_trip() {
+        let input = "..^.\n.^.^";
+        let grid = Grid::parser(char_match!('.' => Square::Blank, '^' => Square::Splitter))
+            .parse(input)
+            .unwrap();
+        let rendered = grid.render(char_render!('.' => Square::Blank, '^' => Square::Splitter));
+        assert_eq!(rendered, input);
+    }
+
+    #[test]
+    fn test_display_and_overlay() {
+        let grid = grid("...\n.#.\n...");
+        assert_eq!(grid.to_string(), "...\n.#.\n...");
+
+        let beam = (0..3).map(|y| (GridPosition { x: 0, y }, '|'));
+        let outside = [(GridPosition { x: 3, y: 0 }, '!')];
+        assert_eq!(
+            grid.render_overlay(|&c| c, beam.chain(outside)),
+            "|..\n|#.\n|.."
+        );
+    }
+}
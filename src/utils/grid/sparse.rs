@@ -0,0 +1,232 @@
+use super::{Direction, Grid, GridPosition};
+use crate::utils::geometry::Point2;
+use std::collections::HashMap;
+use std::ops::Index;
+
+/// An unbounded grid with signed coordinates, storing only cells that have been
+/// set. Every other position reads as the grid's default value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<i64>, T>,
+    default: T,
+    bounds: Option<(Point2<i64>, Point2<i64>)>,
+}
+
+fn offset(pos: Point2<i64>, dx: i64, dy: i64) -> Point2<i64> {
+    Point2::new([pos.x() + dx, pos.y() + dy])
+}
+
+/// Grows the inclusive `(min, max)` box `bounds` to cover `pos`.
+fn extend(
+    bounds: Option<(Point2<i64>, Point2<i64>)>,
+    pos: Point2<i64>,
+) -> (Point2<i64>, Point2<i64>) {
+    match bounds {
+        None => (pos, pos),
+        Some((min, max)) => (
+            Point2::new([min.x().min(pos.x()), min.y().min(pos.y())]),
+            Point2::new([max.x().max(pos.x()), max.y().max(pos.y())]),
+        ),
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    pub fn get(&self, pos: Point2<i64>) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    pub fn set(&mut self, pos: Point2<i64>, value: T) -> Option<T> {
+        self.bounds = Some(extend(self.bounds, pos));
+        self.cells.insert(pos, value)
+    }
+
+    /// Resets `pos` to the default value, returning what was set there. Shrinks
+    /// the bounds if `pos` lay on their edge.
+    pub fn remove(&mut self, pos: Point2<i64>) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+        let on_edge = self.bounds.is_some_and(|(min, max)| {
+            pos.x() == min.x() || pos.x() == max.x() || pos.y() == min.y() || pos.y() == max.y()
+        });
+        if on_edge {
+            self.bounds = self
+                .cells
+                .keys()
+                .fold(None, |bounds, &cell| Some(extend(bounds, cell)));
+        }
+        Some(removed)
+    }
+
+    pub fn contains(&self, pos: Point2<i64>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Inclusive `(min, max)` corners of the explicitly set cells, or `None` if
+    /// there are none.
+    pub fn bounds(&self) -> Option<(Point2<i64>, Point2<i64>)> {
+        self.bounds
+    }
+
+    /// The explicitly set cells, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    pub fn step(&self, pos: Point2<i64>, dir: Direction) -> Point2<i64> {
+        let (dx, dy) = dir.offset();
+        offset(pos, dx as i64, dy as i64)
+    }
+
+    pub fn neighbors4(&self, pos: Point2<i64>) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.neighbors_in(pos, &Direction::CARDINAL)
+    }
+
+    pub fn neighbors8(&self, pos: Point2<i64>) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.neighbors_in(pos, &Direction::ALL)
+    }
+
+    fn neighbors_in<'a>(
+        &'a self,
+        pos: Point2<i64>,
+        dirs: &'static [Direction],
+    ) -> impl Iterator<Item = (Point2<i64>, &'a T)> {
+        dirs.iter().map(move |&dir| {
+            let next = self.step(pos, dir);
+            (next, self.get(next))
+        })
+    }
+
+    /// Places every cell of `grid` into a sparse grid, with the grid's top-left
+    /// corner at `origin`.
+    pub fn from_grid(grid: Grid<T>, origin: Point2<i64>, default: T) -> Self {
+        let width = grid.width;
+        let mut sparse = Self::new(default);
+        for (i, cell) in grid.cells.into_iter().enumerate() {
+            let (x, y) = ((i % width) as i64, (i / width) as i64);
+            sparse.set(offset(origin, x, y), cell);
+        }
+        sparse
+    }
+
+    /// Copies the cells within [`SparseGrid::bounds`] into a dense grid, returning
+    /// it with the position of its top-left corner. `None` if no cells are set.
+    pub fn to_grid(&self) -> Option<(Grid<T>, Point2<i64>)>
+    where
+        T: Clone,
+    {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let (min, max) = self.bounds?;
+        let rows = (min.y()..=max.y())
+            .map(|y| (min.x()..=max.x()).map(move |x| self.get(Point2::new([x, y])).clone()));
+        let grid = Grid::from_rows(rows).expect("bounds are non-empty");
+        Some((grid, min))
+    }
+
+    /// Converts a position in a dense grid produced by [`SparseGrid::to_grid`] back
+    /// into sparse coordinates.
+    pub fn from_dense(pos: GridPosition, origin: Point2<i64>) -> Point2<i64> {
+        offset(origin, pos.x as i64, pos.y as i64)
+    }
+}
+
+impl<T> Index<Point2<i64>> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2<i64>) -> &Self::Output {
+        self.get(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::tests::grid;
+
+    fn point(x: i64, y: i64) -> Point2<i64> {
+        Point2::new([x, y])
+    }
+
+    #[test]
+    fn test_negative_coordinates() {
+        let mut world = SparseGrid::new('.');
+        world.set(point(-3, 2), '#');
+        world.set(point(1, -1), '#');
+        assert_eq!(world[point(-3, 2)], '#');
+        assert_eq!(world[point(100, -100)], '.');
+        assert_eq!(world.bounds(), Some((point(-3, -1), point(1, 2))));
+
+        let (dense, origin) = world.to_grid().unwrap();
+        assert_eq!(origin, point(-3, -1));
+        assert_eq!(dense.render(|&c| c), "....#\n.....\n.....\n#....");
+
+        assert_eq!(world.remove(point(1, -1)), Some('#'));
+        assert_eq!(world.remove(point(1, -1)), None);
+        assert_eq!(world.len(), 1);
+        assert_eq!(world.bounds(), Some((point(-3, 2), point(-3, 2))));
+        assert_eq!(
+            world
+                .neighbors8(point(-2, 1))
+                .filter(|(_, c)| **c == '#')
+                .count(),
+            1
+        );
+        assert_eq!(
+            world
+                .neighbors4(point(-2, 1))
+                .filter(|(_, c)| **c == '#')
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let grid = grid("#.\n.#\n##");
+        let sparse = SparseGrid::from_grid(grid.clone(), point(-5, 7), ' ');
+        assert_eq!(sparse[point(-5, 7)], '#');
+        assert_eq!(sparse[point(-4, 9)], '#');
+        assert_eq!(
+            SparseGrid::<char>::from_dense(GridPosition { x: 1, y: 2 }, point(-5, 7)),
+            point(-4, 9)
+        );
+        assert_eq!(sparse.to_grid(), Some((grid, point(-5, 7))));
+        assert_eq!(SparseGrid::new(0).to_grid(), None);
+    }
+
+    #[test]
+    fn test_bounds_follow_removals() {
+        let mut world = SparseGrid::new(0);
+        for (x, y) in [(0, 0), (2, 1), (5, 3), (1, 4)] {
+            world.set(point(x, y), 1);
+        }
+        world.remove(point(2, 1));
+        assert_eq!(world.bounds(), Some((point(0, 0), point(5, 4))));
+        world.remove(point(5, 3));
+        assert_eq!(world.bounds(), Some((point(0, 0), point(1, 4))));
+        world.remove(point(0, 0));
+        world.remove(point(1, 4));
+        assert_eq!(world.bounds(), None);
+        assert_eq!(world.to_grid(), None);
+    }
+}
@@ -0,0 +1,358 @@
+use super::{Direction, Grid, GridPosition};
+use crate::utils::search::{self, Cost, SearchResult};
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+/// Rules for what happens when a step crosses the edge of a `width` x `height`
+/// grid.
+///
+/// Returns where the step lands and the direction faced afterwards, or `None` if
+/// it leaves the grid. Most topologies keep the direction unchanged; folding
+/// rules such as cube-face mappings may turn it.
+///
+/// Implemented for closures with the same signature as [`Topology::step`], so
+/// one-off edge rules can be written inline.
+pub trait Topology {
+    fn step(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+        width: usize,
+        height: usize,
+    ) -> Option<(GridPosition, Direction)>;
+
+    /// A lower bound on the number of 4-connected steps from `from` to `to`, used
+    /// as the A* heuristic in [`TopologyView::shortest_path`]. The default of 0 is
+    /// valid for any topology.
+    fn min_distance(&self, _: GridPosition, _: GridPosition, _: usize, _: usize) -> usize {
+        0
+    }
+}
+
+impl<F> Topology for F
+where
+    F: Fn(GridPosition, Direction, usize, usize) -> Option<(GridPosition, Direction)>,
+{
+    fn step(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+        width: usize,
+        height: usize,
+    ) -> Option<(GridPosition, Direction)> {
+        self(pos, dir, width, height)
+    }
+}
+
+/// Steps off the edge leave the grid. This is how [`Grid`] itself behaves.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Bounded;
+
+/// Steps off one edge re-enter from the opposite edge, making the grid a torus.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Wrapping;
+
+/// Steps off the edge stay on the edge.
+///
+/// Neighbourhoods at the edge therefore include the cell itself, and some cells more
+/// than once: the 8 neighbours of a corner are the corner three times and each
+/// adjacent edge cell twice, which inflates anything counted over a neighbourhood,
+/// such as a cellular automaton's live neighbours.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Clamped;
+
+impl Topology for Bounded {
+    fn step(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+        width: usize,
+        height: usize,
+    ) -> Option<(GridPosition, Direction)> {
+        let (dx, dy) = dir.offset();
+        let x = pos.x.checked_add_signed(dx)?;
+        let y = pos.y.checked_add_signed(dy)?;
+        (x < width && y < height).then_some((GridPosition { x, y }, dir))
+    }
+
+    fn min_distance(&self, from: GridPosition, to: GridPosition, _: usize, _: usize) -> usize {
+        from.x.abs_diff(to.x) + from.y.abs_diff(to.y)
+    }
+}
+
+impl Topology for Wrapping {
+    fn step(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+        width: usize,
+        height: usize,
+    ) -> Option<(GridPosition, Direction)> {
+        let (dx, dy) = dir.offset();
+        let wrap =
+            |v: usize, d: isize, len: usize| (v as isize + d).rem_euclid(len as isize) as usize;
+        let pos = GridPosition {
+            x: wrap(pos.x, dx, width),
+            y: wrap(pos.y, dy, height),
+        };
+        Some((pos, dir))
+    }
+
+    fn min_distance(
+        &self,
+        from: GridPosition,
+        to: GridPosition,
+        width: usize,
+        height: usize,
+    ) -> usize {
+        let around = |a: usize, b: usize, len: usize| {
+            let d = a.abs_diff(b);
+            d.min(len - d)
+        };
+        around(from.x, to.x, width) + around(from.y, to.y, height)
+    }
+}
+
+impl Topology for Clamped {
+    fn step(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+        width: usize,
+        height: usize,
+    ) -> Option<(GridPosition, Direction)> {
+        let (dx, dy) = dir.offset();
+        let clamp = |v: usize, d: isize, len: usize| v.saturating_add_signed(d).min(len - 1);
+        let pos = GridPosition {
+            x: clamp(pos.x, dx, width),
+            y: clamp(pos.y, dy, height),
+        };
+        Some((pos, dir))
+    }
+
+    fn min_distance(&self, from: GridPosition, to: GridPosition, _: usize, _: usize) -> usize {
+        from.x.abs_diff(to.x) + from.y.abs_diff(to.y)
+    }
+}
+
+/// A borrowed view of a [`Grid`] whose neighbour methods follow a [`Topology`].
+///
+/// On grids narrower than three cells, wrapping neighbourhoods can contain the
+/// same position more than once.
+#[derive(Clone, Debug)]
+pub struct TopologyView<'a, T, Tp> {
+    grid: &'a Grid<T>,
+    topology: Tp,
+}
+
+impl<T> Grid<T> {
+    pub fn with_topology<Tp: Topology>(&self, topology: Tp) -> TopologyView<'_, T, Tp> {
+        TopologyView {
+            grid: self,
+            topology,
+        }
+    }
+
+    /// Views the grid as a torus.
+    pub fn wrapping(&self) -> TopologyView<'_, T, Wrapping> {
+        self.with_topology(Wrapping)
+    }
+}
+
+impl<'a, T, Tp: Topology> TopologyView<'a, T, Tp> {
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    pub fn step(&self, pos: GridPosition, dir: Direction) -> Option<GridPosition> {
+        self.step_facing(pos, dir).map(|(next, _)| next)
+    }
+
+    /// Like [`TopologyView::step`], but also returns the direction faced after the
+    /// step.
+    pub fn step_facing(
+        &self,
+        pos: GridPosition,
+        dir: Direction,
+    ) -> Option<(GridPosition, Direction)> {
+        self.topology
+            .step(pos, dir, self.grid.width, self.grid.height)
+    }
+
+    pub fn neighbors4(&self, pos: GridPosition) -> impl Iterator<Item = (GridPosition, &'a T)> {
+        neighbors_in(self, pos, &Direction::CARDINAL)
+    }
+
+    pub fn neighbors8(&self, pos: GridPosition) -> impl Iterator<Item = (GridPosition, &'a T)> {
+        neighbors_in(self, pos, &Direction::ALL)
+    }
+
+    /// Breadth-first search over the 4-connected cells for which `passable` holds.
+    pub fn bfs_from(
+        &self,
+        start: GridPosition,
+        passable: impl Fn(&T) -> bool,
+    ) -> SearchResult<GridPosition, usize> {
+        search::bfs(start, |&pos| {
+            self.neighbors4(pos)
+                .filter(|(_, cell)| passable(cell))
+                .map(|(next, _)| next)
+        })
+    }
+
+    /// Dijkstra over 4-connected cells, where `cost` gives the cost of stepping onto
+    /// a cell, or `None` if it cannot be entered.
+    pub fn dijkstra_from<C: Cost>(
+        &self,
+        start: GridPosition,
+        cost: impl Fn(&T) -> Option<C>,
+    ) -> SearchResult<GridPosition, C> {
+        search::dijkstra(start, |&pos| {
+            self.neighbors4(pos)
+                .filter_map(|(next, cell)| Some((next, cost(cell)?)))
+        })
+    }
+
+    /// Shortest 4-connected path from `start` to `goal` through cells for which
+    /// `passable` holds, found with A* guided by [`Topology::min_distance`].
+    pub fn shortest_path(
+        &self,
+        start: GridPosition,
+        goal: GridPosition,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Vec<GridPosition>> {
+        let (path, _) = search::astar(
+            start,
+            |&pos| {
+                self.neighbors4(pos)
+                    .filter(|(_, cell)| passable(cell))
+                    .map(|(next, _)| (next, 1))
+            },
+            |&pos| {
+                self.topology
+                    .min_distance(pos, goal, self.grid.width, self.grid.height)
+            },
+            |&pos| pos == goal,
+        )?;
+        Some(path)
+    }
+
+    /// All cells 4-connected to `start` through cells matching `pred`. Empty if
+    /// `start` itself does not match.
+    pub fn flood_fill(
+        &self,
+        start: GridPosition,
+        pred: impl Fn(&T) -> bool,
+    ) -> HashSet<GridPosition> {
+        if !pred(&self.grid[start]) {
+            return HashSet::new();
+        }
+        self.bfs_from(start, pred).distances.into_keys().collect()
+    }
+}
+
+/// The neighbours of `pos` in `dirs` under `view`'s topology. Takes the view by
+/// value or by reference, so [`Grid`] can hand out iterators over a temporary
+/// [`Bounded`] view.
+pub(super) fn neighbors_in<'a, T: 'a, Tp: Topology>(
+    view: impl Borrow<TopologyView<'a, T, Tp>>,
+    pos: GridPosition,
+    dirs: &'static [Direction],
+) -> impl Iterator<Item = (GridPosition, &'a T)> {
+    dirs.iter().filter_map(move |&dir| {
+        let view = view.borrow();
+        let next = view.step(pos, dir)?;
+        Some((next, &view.grid[next]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::tests::grid;
+
+    fn cells<'a>(it: impl Iterator<Item = (GridPosition, &'a char)>) -> String {
+        it.map(|(_, &c)| c).collect()
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let torus = grid("abc\ndef\nghi");
+        let corner = GridPosition { x: 0, y: 0 };
+        assert_eq!(cells(torus.wrapping().neighbors4(corner)), "gbdc");
+        assert_eq!(cells(torus.wrapping().neighbors8(corner)), "ghbedfci");
+        assert_eq!(
+            torus.wrapping().step(corner, Direction::UpLeft),
+            Some(GridPosition { x: 2, y: 2 })
+        );
+    }
+
+    #[test]
+    fn test_clamped_and_bounded() {
+        let g = grid("ab\ncd");
+        let corner = GridPosition { x: 0, y: 0 };
+        assert_eq!(
+            g.with_topology(Clamped).step(corner, Direction::Left),
+            Some(corner)
+        );
+        assert_eq!(g.with_topology(Bounded).step(corner, Direction::Left), None);
+        assert_eq!(
+            cells(g.with_topology(Clamped).neighbors8(corner)),
+            "abbdccaa"
+        );
+        assert_eq!(
+            cells(g.with_topology(Bounded).neighbors8(corner)),
+            cells(g.neighbors8(corner))
+        );
+    }
+
+    #[test]
+    fn test_custom_topology_and_search() {
+        // A cylinder: wraps left-right, bounded top-bottom.
+        let cylinder = |pos: GridPosition, dir: Direction, width: usize, height: usize| {
+            let (next, dir) = Wrapping.step(pos, dir, width, height)?;
+            let (_, dy) = dir.offset();
+            let crossed = (dy < 0 && pos.y == 0) || (dy > 0 && pos.y == height - 1);
+            (!crossed).then_some((next, dir))
+        };
+        let maze = grid("S#.\n.#.\n.#.");
+        let start = maze.find(&'S').unwrap();
+        let goal = GridPosition { x: 2, y: 2 };
+
+        assert_eq!(maze.bfs_from(start, |&c| c != '#').distance(&goal), None);
+        let wrapped = maze.with_topology(cylinder).bfs_from(start, |&c| c != '#');
+        assert_eq!(wrapped.distance(&goal), Some(3));
+        assert_eq!(
+            maze.with_topology(cylinder).step(start, Direction::Up),
+            None
+        );
+        assert_eq!(
+            maze.wrapping()
+                .bfs_from(start, |&c| c != '#')
+                .distance(&goal),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_wrapping_search() {
+        let maze = grid("S.#.\n##..\n...#\n.#.G");
+        let start = maze.find(&'S').unwrap();
+        let goal = maze.find(&'G').unwrap();
+        let torus = maze.wrapping();
+
+        let path = torus.shortest_path(start, goal, |&c| c != '#').unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(maze.shortest_path(start, goal, |&c| c != '#'), None);
+
+        let weighted = torus.dijkstra_from(start, |&c| (c != '#').then_some(2));
+        assert_eq!(weighted.distance(&goal), Some(4));
+        assert_eq!(torus.flood_fill(start, |&c| c != '#').len(), 11);
+        assert_eq!(maze.flood_fill(start, |&c| c != '#').len(), 2);
+        assert!(
+            torus
+                .flood_fill(GridPosition { x: 2, y: 0 }, |&c| c != '#')
+                .is_empty()
+        );
+    }
+}
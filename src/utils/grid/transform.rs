@@ -0,0 +1,281 @@
+use super::{Grid, GridPosition};
+use std::ops::Index;
+
+impl<T: Clone> Grid<T> {
+    /// Builds a `width` x `height` grid where each cell is copied from the position
+    /// in `self` given by `source`.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(GridPosition) -> GridPosition,
+    ) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| GridPosition { x, y }))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Swaps rows and columns, mirroring along the main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |GridPosition { x, y }| {
+            GridPosition { x: y, y: x }
+        })
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let height = self.height;
+        self.remap(self.height, self.width, |GridPosition { x, y }| {
+            GridPosition {
+                x: y,
+                y: height - 1 - x,
+            }
+        })
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let width = self.width;
+        self.remap(self.height, self.width, |GridPosition { x, y }| {
+            GridPosition {
+                x: width - 1 - y,
+                y: x,
+            }
+        })
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        self.remap(self.width, self.height, |GridPosition { x, y }| {
+            GridPosition {
+                x: width - 1 - x,
+                y,
+            }
+        })
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        self.remap(self.width, self.height, |GridPosition { x, y }| {
+            GridPosition {
+                x,
+                y: height - 1 - y,
+            }
+        })
+    }
+
+    /// All eight rotations and reflections of the grid, starting with the grid
+    /// itself. Symmetric grids yield duplicates.
+    pub fn symmetries(&self) -> impl Iterator<Item = Grid<T>> {
+        let rotations = |first: Grid<T>| {
+            std::iter::successors(Some(first), |grid| Some(grid.rotate_cw())).take(4)
+        };
+        rotations(self.clone()).chain(rotations(self.flip_horizontal()))
+    }
+}
+
+/// A borrowed rectangular window into a [`Grid`], indexed relative to its own
+/// top-left corner.
+#[derive(Debug)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    origin: GridPosition,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for SubGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SubGrid<'_, T> {}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` view with its top-left corner at `origin`, or `None` if
+    /// it would be empty or extend past the grid.
+    pub fn sub_grid(
+        &self,
+        origin: GridPosition,
+        width: usize,
+        height: usize,
+    ) -> Option<SubGrid<'_, T>> {
+        let fits = |start: usize, len: usize, limit: usize| {
+            len > 0 && start.checked_add(len).is_some_and(|end| end <= limit)
+        };
+        (fits(origin.x, width, self.width) && fits(origin.y, height, self.height)).then_some(
+            SubGrid {
+                grid: self,
+                origin,
+                width,
+                height,
+            },
+        )
+    }
+
+    /// Every `width` x `height` view that fits in the grid, in row-major order of
+    /// their origins. Yields nothing if either dimension is zero.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = SubGrid<'_, T>> {
+        let count = |len: usize, limit: usize| match len {
+            0 => 0,
+            len => (limit + 1).saturating_sub(len),
+        };
+        let (xs, ys) = (count(width, self.width), count(height, self.height));
+        (0..ys)
+            .flat_map(move |y| (0..xs).map(move |x| GridPosition { x, y }))
+            .filter_map(move |origin| self.sub_grid(origin, width, height))
+    }
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Position of this view's top-left corner in the underlying grid.
+    pub fn origin(&self) -> GridPosition {
+        self.origin
+    }
+
+    /// Converts a position local to this view into one in the underlying grid.
+    pub fn to_parent(&self, pos: GridPosition) -> GridPosition {
+        GridPosition {
+            x: self.origin.x + pos.x,
+            y: self.origin.y + pos.y,
+        }
+    }
+
+    pub fn get(&self, pos: GridPosition) -> Option<&'a T> {
+        (pos.x < self.width && pos.y < self.height).then(|| &self.grid[self.to_parent(pos)])
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "row out of bounds: {y}");
+        let start = self.origin.x;
+        &self.grid.row(self.origin.y + y)[start..start + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    pub fn iter_enumerated(&self) -> impl Iterator<Item = (GridPosition, &'a T)> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, cell)| (GridPosition { x, y }, cell))
+        })
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.rows().flatten().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<GridPosition> for SubGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: GridPosition) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position out of bounds: {pos:?}"))
+    }
+}
+
+impl<T: PartialEq> PartialEq<Grid<T>> for SubGrid<'_, T> {
+    fn eq(&self, other: &Grid<T>) -> bool {
+        self.width == other.width && self.height == other.height && self.rows().eq(other.rows())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::tests::grid;
+    use crate::utils::property::{self, one_of, rectangles};
+
+    #[test]
+    fn test_rotations() {
+        let tile = grid("ab\ncd\nef");
+        assert_eq!(tile.rotate_cw(), grid("eca\nfdb"));
+        assert_eq!(tile.rotate_ccw(), grid("bdf\nace"));
+        assert_eq!(tile.transpose(), grid("ace\nbdf"));
+        assert_eq!(tile.flip_horizontal(), grid("ba\ndc\nfe"));
+        assert_eq!(tile.flip_vertical(), grid("ef\ncd\nab"));
+        assert_eq!(tile.symmetries().count(), 8);
+    }
+
+    #[test]
+    fn test_transform_identities() {
+        property::forall(&rectangles(one_of(&['.', '#', 'S']), 8), |rows| {
+            let grid = Grid::from_rows(rows.clone()).unwrap();
+            let checks = [
+                grid.rotate_cw().rotate_ccw() == grid,
+                grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw() == grid,
+                grid.transpose().transpose() == grid,
+                grid.rotate_cw() == grid.transpose().flip_horizontal(),
+                grid.flip_vertical() == grid.rotate_cw().rotate_cw().flip_horizontal(),
+            ];
+            match checks.iter().position(|ok| !ok) {
+                None => Ok(()),
+                Some(i) => Err(format!("identity {i} failed")),
+            }
+        });
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        let image = grid("#..#\n.##.\n.##.\n#..#");
+        let pattern = grid("##\n##");
+        let matches: Vec<_> = image
+            .windows(2, 2)
+            .filter(|window| *window == pattern)
+            .map(|window| window.origin())
+            .collect();
+        assert_eq!(matches, [GridPosition { x: 1, y: 1 }]);
+
+        let corner = image.sub_grid(GridPosition { x: 2, y: 2 }, 2, 2).unwrap();
+        assert_eq!(corner.to_grid(), grid("#.\n.#"));
+        assert_eq!(corner[GridPosition { x: 1, y: 1 }], '#');
+        assert_eq!(corner.get(GridPosition { x: 2, y: 0 }), None);
+        assert_eq!(
+            corner.to_parent(GridPosition { x: 1, y: 0 }),
+            GridPosition { x: 3, y: 2 }
+        );
+        assert!(image.sub_grid(GridPosition { x: 3, y: 0 }, 2, 1).is_none());
+        assert_eq!(image.windows(5, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_sub_grid_rejects_empty_and_overflowing_views() {
+        let image = grid("ab\ncd");
+        let origin = GridPosition { x: 1, y: 1 };
+        assert!(image.sub_grid(origin, 0, 1).is_none());
+        assert!(image.sub_grid(origin, 1, 0).is_none());
+        assert!(image.sub_grid(origin, usize::MAX, 1).is_none());
+        assert!(image.sub_grid(origin, 1, usize::MAX).is_none());
+        assert_eq!(image.windows(0, 1).count(), 0);
+        assert_eq!(image.windows(1, 0).count(), 0);
+        assert_eq!(image.windows(1, 1).count(), 4);
+    }
+}
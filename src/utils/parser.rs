@@ -1,14 +1,30 @@
 #![allow(dead_code)]
 
+mod boxed;
+mod byte_parser;
 mod char_parser;
 mod error;
+mod map_parser;
+mod recursive;
 mod str_parser;
+mod validation;
 
+pub use boxed::{BoxParser, BoxStrParser};
+pub use byte_parser::{ascii_digit, from_ascii, AsciiInt, ByteParser, ByteSliceParser};
+pub(crate) use byte_parser::byte_lines;
 pub use char_parser::CharParser;
 pub use error::ParseError;
-use std::fmt::Display;
+pub use map_parser::{adjacency_list, key_value, IntoMap, MapCollection};
+pub use recursive::{nested, recursive, Nested, Recursive, RecursiveRef};
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
 use std::str::FromStr;
-pub use str_parser::{lsplit_once, rsplit_once, split_pair, uncons, StrParser};
+pub use validation::{EnsureLen, EnsureRectangular, Filter, InRange};
+pub use str_parser::{
+    ints, ints_array, lsplit_once, prefix_parser, rsplit_once, split_blocks, split_pair, uncons,
+    StrParser,
+};
 
 // === Core Trait ===
 
@@ -86,7 +102,193 @@ pub trait Parser<I> {
     where
         Self: Sized,
     {
-        IntoEach { parser: self }
+        IntoEach {
+            parser: self,
+            collect_errors: false,
+        }
+    }
+
+    /// Reject outputs of this parser for which `predicate` returns `false`.
+    ///
+    /// Fails with [`ParseError::Rejected`], carrying `message` and the rejected value.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::Parser;
+    /// let even = parser::from_str::<u32>.filter(|n| n % 2 == 0, "expected an even number");
+    /// assert_eq!(even.parse("4"), Ok(4));
+    /// assert!(even.parse("5").is_err());
+    /// ```
+    fn filter<F>(self, predicate: F, message: &str) -> Filter<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Output) -> bool,
+    {
+        Filter {
+            parser: self,
+            predicate,
+            message: message.to_string(),
+        }
+    }
+
+    /// Require the `Vec` produced by this parser to have exactly `len` items.
+    ///
+    /// Fails with [`ParseError::WrongItemCount`] otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{ParseError, Parser, StrParser};
+    /// let p = parser::from_str::<u32>.split(",").ensure_len(3);
+    /// assert_eq!(p.parse("1,2,3"), Ok(vec![1, 2, 3]));
+    /// assert_eq!(
+    ///     p.parse("1,2"),
+    ///     Err(ParseError::WrongItemCount { expected: 3, got: 2 })
+    /// );
+    /// ```
+    fn ensure_len(self, len: usize) -> EnsureLen<Self>
+    where
+        Self: Sized,
+    {
+        EnsureLen { parser: self, len }
+    }
+
+    /// Require every row of the nested `Vec` produced by this parser to have the same
+    /// length.
+    ///
+    /// Fails with [`ParseError::Ragged`], naming the first offending row (counting from
+    /// 1), otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{CharParser, ParseError, Parser, StrParser};
+    /// let p = parser::digit::<10>.chars().lines().ensure_rectangular();
+    /// assert_eq!(p.parse("12\n34"), Ok(vec![vec![1, 2], vec![3, 4]]));
+    /// assert_eq!(
+    ///     p.parse("12\n34\n5"),
+    ///     Err(ParseError::Ragged { row: 3, expected: 2, got: 1 })
+    /// );
+    /// ```
+    fn ensure_rectangular(self) -> EnsureRectangular<Self>
+    where
+        Self: Sized,
+    {
+        EnsureRectangular { parser: self }
+    }
+
+    /// Require the output of this parser to lie within `range`.
+    ///
+    /// Fails with [`ParseError::OutOfRange`] otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::Parser;
+    /// let p = parser::from_str::<i32>.in_range(-9..=9);
+    /// assert_eq!(p.parse("-3"), Ok(-3));
+    /// assert!(p.parse("10").is_err());
+    /// ```
+    fn in_range<R>(self, range: R) -> InRange<Self, R>
+    where
+        Self: Sized,
+        R: RangeBounds<Self::Output>,
+    {
+        InRange {
+            parser: self,
+            range,
+        }
+    }
+
+    /// Collect the key-value pairs produced by this parser into a map of type `M`, such
+    /// as a [`HashMap`](std::collections::HashMap) or
+    /// [`BTreeMap`](std::collections::BTreeMap).
+    ///
+    /// Returns [`ParseError::DuplicateKey`] if any key appears more than once.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{ParseError, Parser};
+    /// let p = parser::identity.into_map::<HashMap<_, _>>();
+    /// assert_eq!(p.parse(vec![('a', 1), ('b', 2)]).unwrap()[&'b'], 2);
+    /// assert_eq!(
+    ///     p.parse(vec![('a', 1), ('a', 2)]),
+    ///     Err(ParseError::DuplicateKey("'a'".to_string()))
+    /// );
+    /// ```
+    fn into_map<M>(self) -> IntoMap<Self, M>
+    where
+        Self: Sized,
+    {
+        IntoMap {
+            parser: self,
+            map: PhantomData,
+        }
+    }
+
+    /// Erase the type of this parser behind a shared pointer, producing a [`BoxParser`].
+    ///
+    /// Useful when parsers are chosen at runtime, since every boxed parser with the same
+    /// input and output types has the same type. For `&str` parsers, prefer
+    /// [`StrParser::boxed_str`], which stays usable with inputs of any lifetime.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{BoxParser, Parser};
+    /// let digits: Vec<BoxParser<char, u32>> = vec![
+    ///     parser::digit::<2>.boxed(),
+    ///     parser::digit::<10>.boxed(),
+    ///     parser::digit::<16>.boxed(),
+    /// ];
+    /// let results: Vec<_> = digits.iter().map(|p| p.parse('9').is_ok()).collect();
+    /// assert_eq!(results, vec![false, true, true]);
+    /// ```
+    fn boxed(self) -> BoxParser<I, Self::Output>
+    where
+        Self: Sized + 'static,
+    {
+        BoxParser::new(self)
+    }
+
+    /// Label this parser with `name` for debugging.
+    ///
+    /// With the `trace` feature enabled, every call logs the input this parser received
+    /// and its result to stderr, indented by how deeply it is nested inside other traced
+    /// parsers. Without the feature the wrapper is a zero-cost pass-through.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{Parser, StrParser};
+    /// let p = parser::lsplit_once(
+    ///     parser::from_str::<u32>.trace("first"),
+    ///     parser::from_str::<u32>.trace("rest").split(","),
+    ///     " ",
+    /// )
+    /// .trace("line");
+    /// assert_eq!(p.parse("1 2,3"), Ok((1, vec![2, 3])));
+    /// ```
+    ///
+    /// With `--features trace`, the call above logs:
+    /// ```text
+    /// line <- "1 2,3"
+    ///   first <- "1"
+    ///   first -> Ok(1)
+    ///   rest <- "2"
+    ///   rest -> Ok(2)
+    ///   rest <- "3"
+    ///   rest -> Ok(3)
+    /// line -> Ok((1, [2, 3]))
+    /// ```
+    fn trace(self, name: &'static str) -> Trace<Self>
+    where
+        Self: Sized,
+    {
+        Trace { parser: self, name }
     }
 }
 
@@ -150,6 +352,27 @@ where
 /// Constructed via [`Parser::into_each`].
 pub struct IntoEach<P> {
     parser: P,
+    collect_errors: bool,
+}
+
+impl<P> IntoEach<P> {
+    /// Parses every item even after a failure, reporting all failures together as a
+    /// [`ParseError::Many`] keyed by 1-based item position.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{ParseError, Parser};
+    /// let p = parser::digit::<10>.into_each().collect_errors();
+    /// let Err(ParseError::Many { errors, .. }) = p.parse("1x3y".chars()) else { panic!() };
+    /// assert_eq!(errors.iter().map(|(pos, _)| *pos).collect::<Vec<_>>(), vec![2, 4]);
+    /// ```
+    pub fn collect_errors(self) -> Self {
+        Self {
+            collect_errors: true,
+            ..self
+        }
+    }
 }
 
 impl<P, C> Parser<C> for IntoEach<P>
@@ -160,12 +383,102 @@ where
     type Output = Vec<P::Output>;
 
     /// Parses each item in `input` using the inner parser, collecting successes into a
-    /// `Vec`. Returns the first error encountered, if any.
+    /// `Vec`. Returns the first error encountered, or every error if
+    /// [`collect_errors`](IntoEach::collect_errors) is set.
     fn parse(&self, input: C) -> Result<Self::Output, ParseError> {
-        input
-            .into_iter()
-            .map(|item| self.parser.parse(item))
-            .collect()
+        let results = input.into_iter().map(|item| self.parser.parse(item));
+        collect_results(results, self.collect_errors, "item")
+    }
+}
+
+/// A parser that logs its input and result under a name when the `trace` feature is on.
+///
+/// Constructed via [`Parser::trace`].
+pub struct Trace<P> {
+    parser: P,
+    name: &'static str,
+}
+
+#[cfg(not(feature = "trace"))]
+impl<I, P> Parser<I> for Trace<P>
+where
+    I: Debug,
+    P: Parser<I>,
+    P::Output: Debug,
+{
+    type Output = P::Output;
+
+    #[inline(always)]
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        self.parser.parse(input)
+    }
+}
+
+#[cfg(feature = "trace")]
+impl<I, P> Parser<I> for Trace<P>
+where
+    I: Debug,
+    P: Parser<I>,
+    P::Output: Debug,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        use std::cell::Cell;
+
+        thread_local! {
+            static DEPTH: Cell<usize> = const { Cell::new(0) };
+        }
+        const MAX_LOGGED_LEN: usize = 80;
+
+        let truncated = |s: String| match s.char_indices().nth(MAX_LOGGED_LEN) {
+            Some((i, _)) => format!("{}...", &s[..i]),
+            None => s,
+        };
+
+        let name = self.name;
+        let depth = DEPTH.get();
+        let indent = "  ".repeat(depth);
+        eprintln!("{indent}{name} <- {}", truncated(format!("{input:?}")));
+
+        DEPTH.set(depth + 1);
+        let result = self.parser.parse(input);
+        DEPTH.set(depth);
+
+        match &result {
+            Ok(output) => eprintln!("{indent}{name} -> Ok({})", truncated(format!("{output:?}"))),
+            Err(err) => eprintln!("{indent}{name} -> Err({err})"),
+        }
+        result
+    }
+}
+
+/// Collects per-item parse results into a `Vec`.
+///
+/// Fails fast on the first error unless `collect_errors` is set, in which case every item
+/// is parsed and all failures are returned as a [`ParseError::Many`], each tagged with
+/// its 1-based position and described as an `item`.
+fn collect_results<T>(
+    results: impl Iterator<Item = Result<T, ParseError>>,
+    collect_errors: bool,
+    item: &'static str,
+) -> Result<Vec<T>, ParseError> {
+    if !collect_errors {
+        return results.collect();
+    }
+
+    let mut outputs = Vec::new();
+    let mut errors = Vec::new();
+    for (i, result) in results.enumerate() {
+        match result {
+            Ok(output) => outputs.push(output),
+            Err(err) => errors.push((i + 1, err)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(outputs)
+    } else {
+        Err(ParseError::Many { item, errors })
     }
 }
 
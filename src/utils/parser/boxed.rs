@@ -0,0 +1,72 @@
+use crate::utils::parser::{ParseError, Parser};
+use std::rc::Rc;
+
+/// A type-erased, cheaply clonable parser from `I` to `O`.
+///
+/// Every `BoxParser<I, O>` has the same type regardless of how it was built, so parsers
+/// chosen at runtime can be returned from different branches, stored in struct fields or
+/// kept in a `Vec`. Cloning shares the underlying parser.
+///
+/// Constructed via [`Parser::boxed`].
+pub struct BoxParser<I, O> {
+    parser: Rc<dyn Parser<I, Output = O>>,
+}
+
+impl<I, O> BoxParser<I, O> {
+    pub(super) fn new(parser: impl Parser<I, Output = O> + 'static) -> Self {
+        Self {
+            parser: Rc::new(parser),
+        }
+    }
+}
+
+impl<I, O> Clone for BoxParser<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: Rc::clone(&self.parser),
+        }
+    }
+}
+
+impl<I, O> Parser<I> for BoxParser<I, O> {
+    type Output = O;
+
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        self.parser.parse(input)
+    }
+}
+
+/// A type-erased, cheaply clonable `&str` parser producing `O`.
+///
+/// Unlike a `BoxParser<&'a str, O>`, which is tied to a single input lifetime, this
+/// accepts string slices of any lifetime, so it remains a
+/// [`StrParser`](crate::utils::parser::StrParser) and keeps all of its combinators.
+///
+/// Constructed via [`StrParser::boxed_str`](crate::utils::parser::StrParser::boxed_str).
+pub struct BoxStrParser<O> {
+    parser: Rc<dyn for<'a> Parser<&'a str, Output = O>>,
+}
+
+impl<O> BoxStrParser<O> {
+    pub(super) fn new(parser: impl for<'a> Parser<&'a str, Output = O> + 'static) -> Self {
+        Self {
+            parser: Rc::new(parser),
+        }
+    }
+}
+
+impl<O> Clone for BoxStrParser<O> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: Rc::clone(&self.parser),
+        }
+    }
+}
+
+impl<O> Parser<&str> for BoxStrParser<O> {
+    type Output = O;
+
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        self.parser.parse(input)
+    }
+}
@@ -0,0 +1,250 @@
+use crate::utils::parser::{collect_results, ParseError, Parser};
+
+/// A [`Parser`] specialised for single-byte inputs, the ASCII counterpart to
+/// [`CharParser`](crate::utils::parser::CharParser).
+///
+/// Any function `Fn(u8) -> Result<T, ParseError>` gains [`bytes`](ByteParser::bytes)
+/// automatically via the blanket impl below.
+pub trait ByteParser: Parser<u8> {
+    /// Wraps this parser so it applies to every byte in a `&[u8]`, collecting results
+    /// into a `Vec`.
+    ///
+    /// Fails fast on the first byte that does not parse.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{ByteParser, Parser};
+    /// let p = parser::ascii_digit::<10>.bytes();
+    /// assert_eq!(p.parse(b"123"), Ok(vec![1, 2, 3]));
+    /// assert!(p.parse(b"12x").is_err());
+    /// ```
+    fn bytes(self) -> Bytes<Self>
+    where
+        Self: Sized,
+    {
+        Bytes { parser: self }
+    }
+}
+
+/// Blanket [`ByteParser`] implementation for functions `Fn(u8) -> Result<T, ParseError>`.
+impl<T, F: Fn(u8) -> Result<T, ParseError>> ByteParser for F {}
+
+/// A [`Parser`] specialised for `&[u8]` inputs, the byte-slice counterpart to
+/// [`StrParser`](crate::utils::parser::StrParser).
+///
+/// Skips UTF-8 decoding entirely, which makes it the faster choice for large ASCII
+/// inputs such as grids.
+pub trait ByteSliceParser: for<'a> Parser<&'a [u8]> {
+    /// Applies this parser to each line of the input, collecting results into a `Vec`.
+    ///
+    /// Lines are split by `\n` or `\r\n`, with the same rules as [`str::lines`], scanning
+    /// for line endings a word at a time. Fails fast on the first line that does not
+    /// parse.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{ByteSliceParser, Parser};
+    /// let p = parser::from_ascii::<i32>.lines();
+    /// assert_eq!(p.parse(b"1\r\n-2\n3\n"), Ok(vec![1, -2, 3]));
+    /// ```
+    fn lines(self) -> ByteLines<Self>
+    where
+        Self: Sized,
+    {
+        ByteLines {
+            parser: self,
+            collect_errors: false,
+        }
+    }
+}
+
+/// Blanket [`ByteSliceParser`] implementation for any type that implements
+/// `Parser<&[u8]>`.
+impl<P> ByteSliceParser for P where P: for<'a> Parser<&'a [u8]> {}
+
+/// A `&[u8]` parser that applies an inner [`ByteParser`] to every byte in the input,
+/// collecting the results into a `Vec`.
+///
+/// Constructed via [`ByteParser::bytes`].
+pub struct Bytes<P> {
+    parser: P,
+}
+
+impl<P: Parser<u8>> Parser<&[u8]> for Bytes<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: &[u8]) -> Result<Self::Output, ParseError> {
+        input.iter().map(|&b| self.parser.parse(b)).collect()
+    }
+}
+
+/// A `&[u8]` parser that applies an inner [`ByteSliceParser`] to each line of the input.
+///
+/// Constructed via [`ByteSliceParser::lines`].
+pub struct ByteLines<P> {
+    parser: P,
+    collect_errors: bool,
+}
+
+impl<P> ByteLines<P> {
+    /// Parses every line even after a failure, reporting all failures together as a
+    /// [`ParseError::Many`] keyed by 1-based line number.
+    pub fn collect_errors(self) -> Self {
+        Self {
+            collect_errors: true,
+            ..self
+        }
+    }
+}
+
+impl<P, T> Parser<&[u8]> for ByteLines<P>
+where
+    P: ByteSliceParser<Output = T>,
+{
+    type Output = Vec<T>;
+
+    fn parse(&self, input: &[u8]) -> Result<Self::Output, ParseError> {
+        let results = byte_lines(input).map(|l| self.parser.parse(l));
+        collect_results(results, self.collect_errors, "line")
+    }
+}
+
+/// Yields the lines of `input`, split by `\n` and with any trailing `\r` removed.
+pub(crate) fn byte_lines(mut input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    std::iter::from_fn(move || {
+        if input.is_empty() {
+            return None;
+        }
+        let line = match find_byte(b'\n', input) {
+            Some(i) => {
+                let line = &input[..i];
+                input = &input[i + 1..];
+                line
+            }
+            None => std::mem::take(&mut input),
+        };
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    })
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+///
+/// Compares eight bytes at a time using the classic "has zero byte" bit trick, falling
+/// back to a byte-wise scan for the tail.
+fn find_byte(needle: u8, haystack: &[u8]) -> Option<usize> {
+    const LO: u64 = 0x0101_0101_0101_0101;
+    const HI: u64 = 0x8080_8080_8080_8080;
+    let repeated = LO * needle as u64;
+
+    let mut chunks = haystack.chunks_exact(8);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        // Bytes equal to `needle` become zero, and only the lowest zero byte is
+        // guaranteed to set its high bit, which is the one we want.
+        let word = u64::from_le_bytes(chunk.try_into().unwrap()) ^ repeated;
+        let zero_bytes = word.wrapping_sub(LO) & !word & HI;
+        if zero_bytes != 0 {
+            return Some(offset + (zero_bytes.trailing_zeros() / 8) as usize);
+        }
+        offset += 8;
+    }
+    chunks
+        .remainder()
+        .iter()
+        .position(|&b| b == needle)
+        .map(|i| offset + i)
+}
+
+/// Integer types that can be parsed directly from ASCII digits by [`from_ascii`].
+pub trait AsciiInt: Sized {
+    fn from_ascii(bytes: &[u8]) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_ascii_int {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl AsciiInt for $t {
+                fn from_ascii(bytes: &[u8]) -> Result<Self, ParseError> {
+                    let (negative, digits) = match bytes {
+                        [b'-', rest @ ..] => (true, rest),
+                        [b'+', rest @ ..] => (false, rest),
+                        _ => (false, bytes),
+                    };
+                    if digits.is_empty() {
+                        return Err(ParseError::EmptyInput);
+                    }
+
+                    // Accumulating negative numbers downwards lets `MIN` parse without
+                    // overflow, and makes any negative unsigned number fail.
+                    let mut n: $t = 0;
+                    for &b in digits {
+                        let digit = b.wrapping_sub(b'0');
+                        if digit > 9 {
+                            return Err(ParseError::NotADigit(b as char));
+                        }
+                        n = n
+                            .checked_mul(10)
+                            .and_then(|n| {
+                                if negative {
+                                    n.checked_sub(digit as $t)
+                                } else {
+                                    n.checked_add(digit as $t)
+                                }
+                            })
+                            .ok_or_else(|| {
+                                ParseError::Other(format!(
+                                    "\"{}\" is out of range for {}",
+                                    String::from_utf8_lossy(bytes),
+                                    stringify!($t),
+                                ))
+                            })?;
+                    }
+                    Ok(n)
+                }
+            }
+        )+
+    };
+}
+
+impl_ascii_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Parses a byte slice of ASCII digits, with an optional leading `+` or `-`, into any
+/// [`AsciiInt`] integer type.
+///
+/// A fast alternative to [`from_str`](crate::utils::parser::from_str) that skips UTF-8
+/// validation and [`FromStr`](std::str::FromStr) entirely. Returns
+/// [`ParseError::EmptyInput`] if there are no digits, [`ParseError::NotADigit`] on the
+/// first non-digit byte, and [`ParseError::Other`] if the value does not fit in `T`.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::utils::parser;
+/// assert_eq!(parser::from_ascii::<u64>(b"1234"), Ok(1234));
+/// assert_eq!(parser::from_ascii::<i8>(b"-128"), Ok(-128));
+/// assert!(parser::from_ascii::<u8>(b"256").is_err());
+/// assert!(parser::from_ascii::<u32>(b"-1").is_err());
+/// ```
+pub fn from_ascii<T: AsciiInt>(bytes: &[u8]) -> Result<T, ParseError> {
+    T::from_ascii(bytes)
+}
+
+/// Interprets an ASCII byte as a digit in the given `RADIX`, returning its numeric value.
+///
+/// The byte counterpart to [`digit`](crate::utils::parser::digit).
+///
+/// # Example
+/// ```
+/// # use aoc_lib::utils::parser;
+/// assert_eq!(parser::ascii_digit::<10>(b'7'), Ok(7));
+/// assert_eq!(parser::ascii_digit::<16>(b'f'), Ok(15));
+/// assert!(parser::ascii_digit::<10>(b'z').is_err());
+/// ```
+pub fn ascii_digit<const RADIX: u32>(b: u8) -> Result<u32, ParseError> {
+    (b as char)
+        .to_digit(RADIX)
+        .ok_or(ParseError::NotADigit(b as char))
+}
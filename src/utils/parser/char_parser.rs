@@ -1,4 +1,4 @@
-use crate::utils::parser::{ParseError, Parser};
+use crate::utils::parser::{collect_results, ParseError, Parser};
 
 /// A [`Parser`] specialised for `char` inputs, with combinators for bridging into
 /// string-slice parsing.
@@ -43,7 +43,10 @@ pub trait CharParser: Parser<char> {
     where
         Self: Sized,
     {
-        Chars { parser: self }
+        Chars {
+            parser: self,
+            collect_errors: false,
+        }
     }
 }
 
@@ -93,6 +96,18 @@ where
 /// Constructed via [`CharParser::chars`].
 pub struct Chars<P> {
     parser: P,
+    collect_errors: bool,
+}
+
+impl<P> Chars<P> {
+    /// Parses every character even after a failure, reporting all failures together as a
+    /// [`ParseError::Many`] keyed by 1-based character position.
+    pub fn collect_errors(self) -> Self {
+        Self {
+            collect_errors: true,
+            ..self
+        }
+    }
 }
 
 impl<P: Parser<char>> Parser<&str> for Chars<P> {
@@ -100,9 +115,11 @@ impl<P: Parser<char>> Parser<&str> for Chars<P> {
 
     /// Applies the inner parser to each character of `input` in order.
     ///
-    /// Returns the first error encountered, if any.
+    /// Returns the first error encountered, or every error if
+    /// [`collect_errors`](Chars::collect_errors) is set.
     fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
-        input.chars().map(|c| self.parser.parse(c)).collect()
+        let results = input.chars().map(|c| self.parser.parse(c));
+        collect_results(results, self.collect_errors, "character")
     }
 }
 
@@ -145,3 +162,27 @@ macro_rules! char_match {
         }
     };
 }
+
+/// The inverse of [`char_match!`]: takes the same arms and builds a `&T -> char`
+/// closure mapping each value back to its character.
+///
+/// Values must be usable as patterns, such as unit enum variants or literals.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::{char_match, char_render};
+/// # #[derive(Debug, PartialEq)]
+/// # enum Square { Blank, Wall }
+/// let parse = char_match! { '.' => Square::Blank, '#' => Square::Wall };
+/// let render = char_render! { '.' => Square::Blank, '#' => Square::Wall };
+/// assert_eq!(render(&Square::Wall), '#');
+/// assert_eq!(parse(render(&Square::Blank)), Ok(Square::Blank));
+/// ```
+#[macro_export]
+macro_rules! char_render {
+    ($($c:literal => $val:pat),+ $(,)?) => {
+        |value: &_| match value {
+            $($val => $c,)+
+        }
+    };
+}
@@ -14,6 +14,45 @@ pub enum ParseError {
         open: String,
         close: String,
     },
+    Rejected {
+        message: String,
+        value: String,
+    },
+    Ragged {
+        row: usize,
+        expected: usize,
+        got: usize,
+    },
+    OutOfRange {
+        value: String,
+        range: String,
+    },
+    MissingSeparator {
+        separator: String,
+        input: String,
+    },
+    DuplicateKey(String),
+    Unbalanced {
+        open: String,
+        close: String,
+        input: String,
+    },
+    WrongSectionCount {
+        expected: usize,
+        got: usize,
+    },
+    WrongItemCount {
+        expected: usize,
+        got: usize,
+    },
+    NoMatch {
+        expected: Vec<String>,
+        input: String,
+    },
+    Many {
+        item: &'static str,
+        errors: Vec<(usize, ParseError)>,
+    },
     Other(String),
 }
 
@@ -34,6 +73,41 @@ impl Display for ParseError {
             ParseError::NotWrapped { open, close } => {
                 write!(f, "expected input wrapped in {open} ... {close}")
             }
+            ParseError::Rejected { message, value } => write!(f, "{message}: {value}"),
+            ParseError::Ragged { row, expected, got } => {
+                write!(f, "expected row {row} to have length {expected}, got {got}")
+            }
+            ParseError::OutOfRange { value, range } => {
+                write!(f, "{value} is out of range {range}")
+            }
+            ParseError::MissingSeparator { separator, input } => {
+                write!(f, "expected \"{separator}\" in \"{input}\"")
+            }
+            ParseError::DuplicateKey(key) => write!(f, "duplicate key {key}"),
+            ParseError::Unbalanced { open, close, input } => {
+                write!(f, "unbalanced {open} ... {close} in \"{input}\"")
+            }
+            ParseError::WrongSectionCount { expected, got } => {
+                write!(f, "expected {expected} sections, got {got}")
+            }
+            ParseError::WrongItemCount { expected, got } => {
+                write!(f, "expected {expected} items, got {got}")
+            }
+            ParseError::NoMatch { expected, input } => {
+                let expected = expected
+                    .iter()
+                    .map(|token| format!("\"{token}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "expected one of {expected}, got \"{input}\"")
+            }
+            ParseError::Many { item, errors } => {
+                write!(f, "{} {item}(s) failed to parse:", errors.len())?;
+                for (position, err) in errors {
+                    write!(f, "\n  {item} {position}: {err}")?;
+                }
+                Ok(())
+            }
             ParseError::Other(msg) => write!(f, "{msg}"),
         }
     }
@@ -0,0 +1,165 @@
+use crate::utils::parser::{as_string, ParseError, Parser, StrParser};
+use std::collections::{btree_map, hash_map, BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+
+/// A map type that [`Parser::into_map`] can collect key-value pairs into.
+pub trait MapCollection: Default {
+    type Key;
+    type Value;
+
+    /// Inserts `value` under `key` if `key` is absent. Otherwise leaves the map
+    /// unchanged and returns [`ParseError::DuplicateKey`] naming the key.
+    fn insert_new(&mut self, key: Self::Key, value: Self::Value) -> Result<(), ParseError>
+    where
+        Self::Key: Debug;
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> MapCollection for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn insert_new(&mut self, key: K, value: V) -> Result<(), ParseError>
+    where
+        K: Debug,
+    {
+        match self.entry(key) {
+            hash_map::Entry::Occupied(entry) => Err(duplicate_key(entry.key())),
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(value);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<K: Ord, V> MapCollection for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn insert_new(&mut self, key: K, value: V) -> Result<(), ParseError>
+    where
+        K: Debug,
+    {
+        match self.entry(key) {
+            btree_map::Entry::Occupied(entry) => Err(duplicate_key(entry.key())),
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(value);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn duplicate_key(key: &impl Debug) -> ParseError {
+    ParseError::DuplicateKey(format!("{key:?}"))
+}
+
+/// A parser that collects key-value pairs produced by an inner parser into a map `M`.
+///
+/// Constructed via [`Parser::into_map`].
+pub struct IntoMap<P, M> {
+    pub(super) parser: P,
+    pub(super) map: PhantomData<fn() -> M>,
+}
+
+impl<I, P, M> Parser<I> for IntoMap<P, M>
+where
+    P: Parser<I>,
+    P::Output: IntoIterator<Item = (M::Key, M::Value)>,
+    M: MapCollection,
+    M::Key: Debug,
+{
+    type Output = M;
+
+    /// Inserts every pair into a new map, returning [`ParseError::DuplicateKey`] for the
+    /// first key that appears twice.
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        let mut map = M::default();
+        for (key, value) in self.parser.parse(input)? {
+            map.insert_new(key, value)?;
+        }
+        Ok(map)
+    }
+}
+
+/// Splits a string on the *first* occurrence of `separator` into a key and a value,
+/// trimming whitespace around both before parsing them.
+///
+/// Returns [`ParseError::MissingSeparator`] if `separator` is not found.
+///
+/// # Example
+/// ```
+/// # use std::collections::BTreeMap;
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::{Parser, StrParser};
+/// let p = parser::key_value(parser::as_string, parser::from_str::<u32>, "=")
+///     .split(",")
+///     .into_map::<BTreeMap<_, _>>();
+/// let map = p.parse("a=1, b = 2").unwrap();
+/// assert_eq!(map["b"], 2);
+/// assert!(p.parse("a=1,a=2").is_err()); // duplicate key
+/// assert!(p.parse("a:1").is_err());     // missing separator
+/// ```
+pub fn key_value<K, V>(
+    key: impl StrParser<Output = K>,
+    value: impl StrParser<Output = V>,
+    separator: &str,
+) -> impl StrParser<Output = (K, V)> {
+    let separator = separator.to_string();
+    move |input: &str| {
+        let (k, v) = input
+            .split_once(&*separator)
+            .ok_or_else(|| ParseError::MissingSeparator {
+                separator: separator.clone(),
+                input: input.to_string(),
+            })?;
+        Ok((key.parse(k.trim())?, value.parse(v.trim())?))
+    }
+}
+
+/// Parses one `node<key_separator>neighbour neighbour ...` line per node into a map from
+/// each node name to the names of its neighbours.
+///
+/// Empty neighbour names (from repeated separators) are skipped. Every neighbour that
+/// has no line of its own is added with an empty list, so the map contains every node in
+/// the graph. Returns [`ParseError::DuplicateKey`] if a node has more than one line.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::Parser;
+/// let p = parser::adjacency_list(":", " ");
+/// let graph = p.parse("you: bbb ccc\nbbb: out").unwrap();
+/// assert_eq!(graph["you"], vec!["bbb", "ccc"]);
+/// assert_eq!(graph["ccc"], Vec::<String>::new());
+/// assert_eq!(graph.len(), 4);
+/// ```
+pub fn adjacency_list(
+    key_separator: &str,
+    neighbour_separator: &str,
+) -> impl StrParser<Output = HashMap<String, Vec<String>>> {
+    let neighbour_separator = neighbour_separator.to_string();
+    let neighbours = move |s: &str| {
+        Ok(s.split(&*neighbour_separator)
+            .filter(|n| !n.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>())
+    };
+    key_value(as_string, neighbours, key_separator)
+        .lines()
+        .into_map::<HashMap<_, _>>()
+        .map(|mut graph| {
+            let leaves: Vec<String> = graph
+                .values()
+                .flatten()
+                .filter(|n| !graph.contains_key(*n))
+                .cloned()
+                .collect();
+            for leaf in leaves {
+                graph.entry(leaf).or_default();
+            }
+            graph
+        })
+}
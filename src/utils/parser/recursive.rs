@@ -0,0 +1,134 @@
+use crate::utils::parser::{BoxStrParser, ParseError, Parser, StrParser};
+use std::cell::OnceCell;
+use std::rc::{Rc, Weak};
+
+/// A `&str` parser that is allowed to refer to itself, for nested structures.
+///
+/// Constructed via [`recursive`].
+pub struct Recursive<T> {
+    parser: Rc<OnceCell<BoxStrParser<T>>>,
+}
+
+impl<T> Parser<&str> for Recursive<T> {
+    type Output = T;
+
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        self.parser
+            .get()
+            .expect("recursive parser is defined")
+            .parse(input)
+    }
+}
+
+/// A handle to the [`Recursive`] parser being defined, usable inside its own definition.
+///
+/// Passed to the closure given to [`recursive`]. Holds only a weak reference, so the
+/// self-reference does not leak the parser.
+pub struct RecursiveRef<T> {
+    parser: Weak<OnceCell<BoxStrParser<T>>>,
+}
+
+impl<T> Clone for RecursiveRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+        }
+    }
+}
+
+impl<T> Parser<&str> for RecursiveRef<T> {
+    type Output = T;
+
+    /// Delegates to the enclosing [`Recursive`] parser.
+    ///
+    /// # Panics
+    /// Panics if called while the parser is still being defined, or after the enclosing
+    /// [`Recursive`] parser has been dropped.
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        let parser = self
+            .parser
+            .upgrade()
+            .expect("recursive parser outlives its references");
+        parser
+            .get()
+            .expect("recursive parser is not used during its own definition")
+            .parse(input)
+    }
+}
+
+/// Constructs a parser that can refer to itself.
+///
+/// `define` receives a [`RecursiveRef`] standing in for the parser being built and
+/// returns its definition, which may use that reference (cloned as often as needed) for
+/// the nested parts.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::{Parser, StrParser};
+/// // Nesting depth of a bracketed expression such as "((()))"
+/// let depth = parser::recursive(|depth| {
+///     move |s: &str| match s {
+///         "" => Ok(0),
+///         _ => depth.clone().wrapped("(", ")").map(|d| d + 1).parse(s),
+///     }
+/// });
+/// assert_eq!(depth.parse("((()))"), Ok(3));
+/// assert!(depth.parse("(()").is_err());
+/// ```
+pub fn recursive<T, P>(define: impl FnOnce(RecursiveRef<T>) -> P) -> Recursive<T>
+where
+    P: StrParser<Output = T> + 'static,
+{
+    let parser = Rc::new(OnceCell::new());
+    let definition = define(RecursiveRef {
+        parser: Rc::downgrade(&parser),
+    });
+    let _ = parser.set(BoxStrParser::new(definition));
+    Recursive { parser }
+}
+
+/// A JSON-like value that is either a single item or a bracketed list of further values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Nested<T> {
+    Item(T),
+    List(Vec<Nested<T>>),
+}
+
+/// Parses arbitrarily nested, comma-separated `[...]` lists of items, such as
+/// `[[1,2],[3,[4]]]`.
+///
+/// Anything not starting with `[` is passed to `item`. Whitespace around items and
+/// brackets is not skipped.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::{Nested, Parser};
+/// let p = parser::nested(parser::from_str::<u32>);
+/// assert_eq!(
+///     p.parse("[1,[],[2,[3]]]"),
+///     Ok(Nested::List(vec![
+///         Nested::Item(1),
+///         Nested::List(vec![]),
+///         Nested::List(vec![Nested::Item(2), Nested::List(vec![Nested::Item(3)])]),
+///     ]))
+/// );
+/// assert!(p.parse("[1,[2]").is_err());
+/// ```
+pub fn nested<T: 'static>(item: impl StrParser<Output = T> + 'static) -> Recursive<Nested<T>> {
+    recursive(|value| {
+        let list = value
+            .balanced_split(",", "[", "]")
+            .wrapped("[", "]")
+            .map(Nested::List);
+        let item = item.map(Nested::Item);
+        move |s: &str| {
+            if s.starts_with('[') {
+                list.parse(s)
+            } else {
+                item.parse(s)
+            }
+        }
+    })
+}
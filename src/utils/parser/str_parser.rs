@@ -1,10 +1,17 @@
-use crate::utils::parser::{CharParser, ParseError, Parser};
+use crate::utils::parser::{
+    collect_results, from_str, BoxStrParser, CharParser, ParseError, Parser,
+};
+use std::fmt::Display;
+use std::str::FromStr;
 
 /// A [`Parser`] specialised for `&str` inputs, with combinators for splitting and
 /// iterating over string data.
 ///
 /// Any type implementing `Parser<&str>` automatically gains these combinators via the
 /// blanket impl below.
+///
+/// The splitting combinators hand their inner parser slices of the input, so with
+/// [`as_str`](crate::utils::parser::as_str) their output can borrow from it.
 pub trait StrParser: for<'a> Parser<&'a str> {
     /// Applies this parser to each line of the input, collecting results into a `Vec`.
     ///
@@ -17,12 +24,18 @@ pub trait StrParser: for<'a> Parser<&'a str> {
     /// # use aoc_lib::utils::parser::{Parser, StrParser};
     /// let p = parser::from_str::<u32>.lines();
     /// assert_eq!(p.parse("1\n2\n3"), Ok(vec![1, 2, 3]));
+    ///
+    /// let borrowed = parser::as_str.lines();
+    /// assert_eq!(borrowed.parse("ab\ncd"), Ok(vec!["ab", "cd"]));
     /// ```
     fn lines(self) -> Lines<Self>
     where
         Self: Sized,
     {
-        Lines { parser: self }
+        Lines {
+            parser: self,
+            collect_errors: false,
+        }
     }
 
     /// Splits the input on `separator` and applies this parser to each part, collecting
@@ -44,6 +57,7 @@ pub trait StrParser: for<'a> Parser<&'a str> {
         Split {
             parser: self,
             separator: separator.to_string(),
+            collect_errors: false,
         }
     }
 
@@ -116,6 +130,168 @@ pub trait StrParser: for<'a> Parser<&'a str> {
             close: close.to_string(),
         }
     }
+
+    /// Splits the input on `separator` like [`split`](StrParser::split), but only where
+    /// the separator is outside every `open`/`close` delimiter pair, and applies this
+    /// parser to each top-level part.
+    ///
+    /// Unlike `split`, an empty input produces an empty `Vec`, so empty lists such as
+    /// `[]` parse naturally. Returns [`ParseError::Unbalanced`] if the delimiters do not
+    /// pair up. Fails fast on the first part that does not parse.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{Parser, StrParser};
+    /// let p = parser::as_string.balanced_split(",", "[", "]");
+    /// assert_eq!(
+    ///     p.parse("1,[2,3],[[4]]"),
+    ///     Ok(vec!["1".to_string(), "[2,3]".to_string(), "[[4]]".to_string()])
+    /// );
+    /// assert!(p.parse("1,[2").is_err());
+    /// ```
+    fn balanced_split(self, separator: &str, open: &str, close: &str) -> BalancedSplit<Self>
+    where
+        Self: Sized,
+    {
+        BalancedSplit {
+            parser: self,
+            separator: separator.to_string(),
+            open: open.to_string(),
+            close: close.to_string(),
+        }
+    }
+
+    /// Erases the type of this parser behind a shared pointer, producing a
+    /// [`BoxStrParser`] that accepts string slices of any lifetime.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{BoxStrParser, Parser, StrParser};
+    /// fn separated(separator: Option<&str>) -> BoxStrParser<Vec<u32>> {
+    ///     match separator {
+    ///         Some(separator) => parser::from_str::<u32>.split(separator).boxed_str(),
+    ///         None => parser::from_str::<u32>.split_whitespace().boxed_str(),
+    ///     }
+    /// }
+    /// assert_eq!(separated(Some(",")).parse("1,2"), Ok(vec![1, 2]));
+    /// assert_eq!(separated(None).lines().parse("1 2\n3"), Ok(vec![vec![1, 2], vec![3]]));
+    /// ```
+    fn boxed_str<T>(self) -> BoxStrParser<T>
+    where
+        Self: StrParser<Output = T> + Sized + 'static,
+    {
+        BoxStrParser::new(self)
+    }
+
+    /// Splits the input into blocks separated by one or more blank lines and applies this
+    /// parser to each block, collecting results into a `Vec`.
+    ///
+    /// Both `\n` and `\r\n` line endings are recognised, lines holding only whitespace
+    /// count as blank, and leading or trailing blank lines never produce empty blocks.
+    /// Each block is passed on without its final line ending. Fails fast on the first
+    /// block that does not parse.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{Parser, StrParser};
+    /// let p = parser::from_str::<u32>.lines().blocks();
+    /// assert_eq!(p.parse("1\n2\n\n3\n"), Ok(vec![vec![1, 2], vec![3]]));
+    /// assert_eq!(p.parse("1\r\n\r\n\r\n2\r\n3"), Ok(vec![vec![1], vec![2, 3]]));
+    /// ```
+    fn blocks(self) -> Blocks<Self>
+    where
+        Self: Sized,
+    {
+        Blocks { parser: self }
+    }
+
+    /// Trims leading and trailing whitespace from the input before applying this parser.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{Parser, StrParser};
+    /// let p = parser::from_str::<u32>.trim();
+    /// assert_eq!(p.parse("  42 "), Ok(42));
+    /// ```
+    fn trim(self) -> Trim<Self>
+    where
+        Self: Sized,
+    {
+        Trim { parser: self }
+    }
+
+    /// Transposes the lines of the input, so that each column becomes a line, and applies
+    /// this parser to the result.
+    ///
+    /// Ragged lines are padded with spaces to the width of the longest line first, so
+    /// every transposed line has the same length.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{Parser, StrParser};
+    /// let p = parser::from_str::<u32>.trim().lines().transpose_lines();
+    /// assert_eq!(p.parse("12\n34\n5"), Ok(vec![135, 24]));
+    /// ```
+    fn transpose_lines(self) -> TransposeLines<Self>
+    where
+        Self: Sized,
+    {
+        TransposeLines { parser: self }
+    }
+
+    /// Splits the input into vertical blocks of columns separated by columns made up
+    /// entirely of whitespace, and applies this parser to each block, collecting results
+    /// into a `Vec`.
+    ///
+    /// Ragged lines are padded with spaces first. Each block is passed on as the lines of
+    /// its columns, in their original orientation. Fails fast on the first block that
+    /// does not parse.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{Parser, StrParser};
+    /// let p = parser::from_str::<u32>.trim().lines().columns_split_on_blank();
+    /// assert_eq!(p.parse("12  3\n 4 56"), Ok(vec![vec![12, 4], vec![3, 56]]));
+    /// ```
+    fn columns_split_on_blank(self) -> ColumnsSplitOnBlank<Self>
+    where
+        Self: Sized,
+    {
+        ColumnsSplitOnBlank { parser: self }
+    }
+
+    /// Splits the input into consecutive fields of the given character `widths` and
+    /// applies this parser to each field, collecting results into a `Vec`.
+    ///
+    /// The final field may be cut short by the end of the input, so lines with their
+    /// trailing whitespace stripped still parse. Returns [`ParseError::WrongLength`] with
+    /// the shortest or longest acceptable length if the input ends before the final
+    /// field starts, or runs past the end of it.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{Parser, StrParser};
+    /// let p = parser::from_str::<u32>.trim().fixed_width(&[3, 2, 4]);
+    /// assert_eq!(p.parse(" 12 3  45"), Ok(vec![12, 3, 45]));
+    /// assert_eq!(p.parse("  1 2 6"), Ok(vec![1, 2, 6]));
+    /// assert!(p.parse("  1 2").is_err());
+    /// ```
+    fn fixed_width(self, widths: &[usize]) -> FixedWidth<Self>
+    where
+        Self: Sized,
+    {
+        FixedWidth {
+            parser: self,
+            widths: widths.to_vec(),
+        }
+    }
 }
 
 /// Blanket [`StrParser`] implementation for any type that implements `Parser<&str>`.
@@ -129,16 +305,37 @@ impl<P> StrParser for P where P: for<'a> Parser<&'a str> {}
 /// Constructed via [`StrParser::lines`].
 pub struct Lines<P> {
     parser: P,
+    collect_errors: bool,
 }
 
-impl<P, T> Parser<&str> for Lines<P>
-where
-    P: StrParser<Output = T>,
-{
-    type Output = Vec<T>;
+impl<P> Lines<P> {
+    /// Parses every line even after a failure, reporting all failures together as a
+    /// [`ParseError::Many`] keyed by 1-based line number.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::{ParseError, Parser, StrParser};
+    /// let p = parser::from_str::<u32>.lines().collect_errors();
+    /// assert_eq!(p.parse("1\n2"), Ok(vec![1, 2]));
+    ///
+    /// let Err(ParseError::Many { errors, .. }) = p.parse("1\nx\n3\n-4") else { panic!() };
+    /// assert_eq!(errors.iter().map(|(line, _)| *line).collect::<Vec<_>>(), vec![2, 4]);
+    /// ```
+    pub fn collect_errors(self) -> Self {
+        Self {
+            collect_errors: true,
+            ..self
+        }
+    }
+}
 
-    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
-        input.lines().map(|l| self.parser.parse(l)).collect()
+impl<'a, P: Parser<&'a str>> Parser<&'a str> for Lines<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: &'a str) -> Result<Self::Output, ParseError> {
+        let results = input.lines().map(|l| self.parser.parse(l));
+        collect_results(results, self.collect_errors, "line")
     }
 }
 
@@ -149,19 +346,26 @@ where
 pub struct Split<P> {
     parser: P,
     separator: String,
+    collect_errors: bool,
 }
 
-impl<P, T> Parser<&str> for Split<P>
-where
-    P: StrParser<Output = T>,
-{
-    type Output = Vec<T>;
+impl<P> Split<P> {
+    /// Parses every part even after a failure, reporting all failures together as a
+    /// [`ParseError::Many`] keyed by 1-based part position.
+    pub fn collect_errors(self) -> Self {
+        Self {
+            collect_errors: true,
+            ..self
+        }
+    }
+}
 
-    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
-        input
-            .split(&self.separator)
-            .map(|v| self.parser.parse(v))
-            .collect()
+impl<'a, P: Parser<&'a str>> Parser<&'a str> for Split<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: &'a str) -> Result<Self::Output, ParseError> {
+        let results = input.split(&self.separator).map(|v| self.parser.parse(v));
+        collect_results(results, self.collect_errors, "part")
     }
 }
 
@@ -173,13 +377,10 @@ pub struct SplitWhitespace<P> {
     parser: P,
 }
 
-impl<P, T> Parser<&str> for SplitWhitespace<P>
-where
-    P: StrParser<Output = T>,
-{
-    type Output = Vec<T>;
+impl<'a, P: Parser<&'a str>> Parser<&'a str> for SplitWhitespace<P> {
+    type Output = Vec<P::Output>;
 
-    fn parse(&self, input: &str) -> Result<Vec<T>, ParseError> {
+    fn parse(&self, input: &'a str) -> Result<Self::Output, ParseError> {
         input
             .split_whitespace()
             .map(|v| self.parser.parse(v))
@@ -248,6 +449,78 @@ where
     }
 }
 
+/// A `&str` parser that splits the input on a separator only at the top level of nested
+/// open/close delimiters, and applies an inner [`StrParser`] to each part.
+///
+/// Constructed via [`StrParser::balanced_split`].
+pub struct BalancedSplit<P> {
+    parser: P,
+    separator: String,
+    open: String,
+    close: String,
+}
+
+impl<P, T> Parser<&str> for BalancedSplit<P>
+where
+    P: StrParser<Output = T>,
+{
+    type Output = Vec<T>;
+
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let unbalanced = || ParseError::Unbalanced {
+            open: self.open.clone(),
+            close: self.close.clone(),
+            input: input.to_string(),
+        };
+
+        let mut outputs = Vec::new();
+        let mut depth: usize = 0;
+        let mut part_start = 0;
+        let mut i = 0;
+        while i < input.len() {
+            let rest = &input[i..];
+            if rest.starts_with(&self.open) {
+                depth += 1;
+                i += self.open.len();
+            } else if rest.starts_with(&self.close) {
+                depth = depth.checked_sub(1).ok_or_else(unbalanced)?;
+                i += self.close.len();
+            } else if depth == 0 && rest.starts_with(&self.separator) {
+                outputs.push(self.parser.parse(&input[part_start..i])?);
+                i += self.separator.len();
+                part_start = i;
+            } else {
+                i += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        if depth != 0 {
+            return Err(unbalanced());
+        }
+        outputs.push(self.parser.parse(&input[part_start..])?);
+        Ok(outputs)
+    }
+}
+
+/// A `&str` parser that splits the input into blank-line separated blocks and applies an
+/// inner [`StrParser`] to each block.
+///
+/// Constructed via [`StrParser::blocks`].
+pub struct Blocks<P> {
+    parser: P,
+}
+
+impl<'a, P: Parser<&'a str>> Parser<&'a str> for Blocks<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: &'a str) -> Result<Self::Output, ParseError> {
+        split_blocks(input).map(|b| self.parser.parse(b)).collect()
+    }
+}
+
 /// Splits a string on `separator` and parses the left and right halves independently.
 ///
 /// Expects exactly one occurrence of `separator`, producing a [`ParseError::WrongLength`]
@@ -260,16 +533,35 @@ where
 /// let p = parser::split_pair(parser::from_str::<u32>, parser::from_str::<u32>, "-");
 /// assert_eq!(p.parse("10-20"), Ok((10, 20)));
 /// ```
-pub fn split_pair<T, U>(
-    left: impl StrParser<Output = T>,
-    right: impl StrParser<Output = U>,
-    separator: &str,
-) -> impl StrParser<Output = (T, U)> {
-    let separator = separator.to_string();
-    move |input: &str| {
-        let elems: Vec<&str> = input.split(&*separator).collect();
+pub fn split_pair<L, R>(left: L, right: R, separator: &str) -> SplitPair<L, R> {
+    SplitPair {
+        left,
+        right,
+        separator: separator.to_string(),
+    }
+}
+
+/// A `&str` parser that splits the input on exactly one occurrence of a separator and
+/// parses the two halves separately.
+///
+/// Constructed via [`split_pair`].
+pub struct SplitPair<L, R> {
+    left: L,
+    right: R,
+    separator: String,
+}
+
+impl<'a, L, R> Parser<&'a str> for SplitPair<L, R>
+where
+    L: Parser<&'a str>,
+    R: Parser<&'a str>,
+{
+    type Output = (L::Output, R::Output);
+
+    fn parse(&self, input: &'a str) -> Result<Self::Output, ParseError> {
+        let elems: Vec<&str> = input.split(&*self.separator).collect();
         match elems.as_slice() {
-            [l, r] => Ok((left.parse(l)?, right.parse(r)?)),
+            [l, r] => Ok((self.left.parse(l)?, self.right.parse(r)?)),
             _ => Err(ParseError::WrongLength {
                 expected: 2,
                 got: elems.len(),
@@ -316,21 +608,46 @@ pub fn uncons<T, U>(
 /// let p = parser::lsplit_once(parser::as_string, parser::as_string, "/");
 /// assert_eq!(p.parse("a/b/c"), Ok(("a".to_string(), "b/c".to_string())));
 /// ```
-pub fn lsplit_once<T, U>(
-    first: impl StrParser<Output = T>,
-    rest: impl StrParser<Output = U>,
-    separator: &str,
-) -> impl StrParser<Output = (T, U)> {
-    move |input: &str| {
-        let (first_part, rest_part) = input.split_once(separator).ok_or(ParseError::EmptyInput)?;
-        Ok((first.parse(first_part)?, rest.parse(rest_part)?))
+pub fn lsplit_once<F, R>(first: F, rest: R, separator: &str) -> LSplitOnce<F, R> {
+    LSplitOnce {
+        first,
+        rest,
+        separator: separator.to_string(),
+    }
+}
+
+/// A `&str` parser that splits the input on the first occurrence of a separator and
+/// parses the two parts separately.
+///
+/// Constructed via [`lsplit_once`].
+pub struct LSplitOnce<F, R> {
+    first: F,
+    rest: R,
+    separator: String,
+}
+
+impl<'a, F, R> Parser<&'a str> for LSplitOnce<F, R>
+where
+    F: Parser<&'a str>,
+    R: Parser<&'a str>,
+{
+    type Output = (F::Output, R::Output);
+
+    fn parse(&self, input: &'a str) -> Result<Self::Output, ParseError> {
+        let (first_part, rest_part) = input
+            .split_once(&*self.separator)
+            .ok_or(ParseError::EmptyInput)?;
+        Ok((self.first.parse(first_part)?, self.rest.parse(rest_part)?))
     }
 }
 
 /// Splits a string on the *last* occurrence of `separator`, parsing each part separately.
 ///
 /// Everything before the final separator is passed to `body`; the trailing segment is
-/// passed to `last`. Returns [`ParseError::EmptyInput`] if `separator` is not found.
+/// passed to `last`. Returns [`ParseError::MissingSeparator`] if `separator` is not found.
+///
+/// The parts may borrow from the input, so `body` can be
+/// [`as_str`](crate::utils::parser::as_str) to keep the leading text for later.
 ///
 /// # Example
 /// ```
@@ -338,14 +655,561 @@ pub fn lsplit_once<T, U>(
 /// # use aoc_lib::utils::parser::Parser;
 /// let p = parser::rsplit_once(parser::as_string, parser::as_string, "/");
 /// assert_eq!(p.parse("a/b/c"), Ok(("a/b".to_string(), "c".to_string())));
+///
+/// let borrowed = parser::rsplit_once(parser::as_str, parser::from_str::<u32>, "\n");
+/// assert_eq!(borrowed.parse("grid\nrows\n7"), Ok(("grid\nrows", 7)));
 /// ```
-pub fn rsplit_once<T, U>(
-    body: impl StrParser<Output = T>,
-    last: impl StrParser<Output = U>,
-    separator: &str,
-) -> impl StrParser<Output = (T, U)> {
-    move |input: &str| {
-        let (rest, last_line) = input.rsplit_once(separator).ok_or(ParseError::EmptyInput)?;
-        Ok((body.parse(rest)?, last.parse(last_line)?))
+pub fn rsplit_once<B, L>(body: B, last: L, separator: &str) -> RSplitOnce<B, L> {
+    RSplitOnce {
+        body,
+        last,
+        separator: separator.to_string(),
+    }
+}
+
+/// A `&str` parser that splits the input on the last occurrence of a separator and
+/// parses the two parts separately.
+///
+/// Constructed via [`rsplit_once`].
+pub struct RSplitOnce<B, L> {
+    body: B,
+    last: L,
+    separator: String,
+}
+
+impl<'a, B, L> Parser<&'a str> for RSplitOnce<B, L>
+where
+    B: Parser<&'a str>,
+    L: Parser<&'a str>,
+{
+    type Output = (B::Output, L::Output);
+
+    fn parse(&self, input: &'a str) -> Result<Self::Output, ParseError> {
+        let (rest, last_part) =
+            input
+                .rsplit_once(&*self.separator)
+                .ok_or_else(|| ParseError::MissingSeparator {
+                    separator: self.separator.clone(),
+                    input: input.to_string(),
+                })?;
+        Ok((self.body.parse(rest)?, self.last.parse(last_part)?))
+    }
+}
+
+/// Extracts every integer from arbitrary text, ignoring everything in between, and parses
+/// each one as a `T`.
+///
+/// A `-` counts as a sign only when it directly precedes a digit and does not follow one,
+/// so `"-5-3"` yields `-5` and `3`, and `"x=-2"` yields `-2`. Parsing a negative number
+/// into an unsigned `T` fails as it would with [`from_str`](crate::utils::parser::from_str).
+///
+/// # Example
+/// ```
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::Parser;
+/// let p = parser::ints::<i32>();
+/// assert_eq!(p.parse("Sensor at x=-2, y=15: range 10-20"), Ok(vec![-2, 15, 10, 20]));
+/// assert_eq!(p.parse("no numbers here"), Ok(vec![]));
+/// ```
+pub fn ints<T>() -> impl StrParser<Output = Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    |input: &str| int_tokens(input).map(from_str).collect()
+}
+
+/// Extracts every integer from arbitrary text like [`ints`], collecting the results into
+/// a fixed-size array of length `N`.
+///
+/// Returns [`ParseError::WrongLength`] if the input does not contain exactly `N` integers.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::Parser;
+/// let p = parser::ints_array::<i64, 2>();
+/// assert_eq!(p.parse("-10--2"), Ok([-10, -2]));
+/// assert!(p.parse("1, 2, 3").is_err());
+/// ```
+pub fn ints_array<T, const N: usize>() -> impl StrParser<Output = [T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    |input: &str| {
+        ints()
+            .parse(input)?
+            .try_into()
+            .map_err(|v: Vec<T>| ParseError::WrongLength {
+                expected: N,
+                got: v.len(),
+                input: input.to_string(),
+            })
+    }
+}
+
+/// Yields the substrings of `input` that form integers, each with an optional leading `-`.
+fn int_tokens(input: &str) -> impl Iterator<Item = &str> {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let is_sign = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_digit());
+            if is_sign || bytes[i].is_ascii_digit() {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                return Some(&input[start..i]);
+            }
+            i += 1;
+        }
+        None
+    })
+}
+
+/// A `&str` parser that trims whitespace from both ends of the input before applying an
+/// inner [`StrParser`].
+///
+/// Constructed via [`StrParser::trim`].
+pub struct Trim<P> {
+    parser: P,
+}
+
+impl<P, T> Parser<&str> for Trim<P>
+where
+    P: StrParser<Output = T>,
+{
+    type Output = T;
+
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        self.parser.parse(input.trim())
+    }
+}
+
+/// A `&str` parser that transposes the lines of the input before applying an inner
+/// [`StrParser`].
+///
+/// Constructed via [`StrParser::transpose_lines`].
+pub struct TransposeLines<P> {
+    parser: P,
+}
+
+impl<P, T> Parser<&str> for TransposeLines<P>
+where
+    P: StrParser<Output = T>,
+{
+    type Output = T;
+
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        let rows = padded_char_rows(input);
+        let width = rows.first().map_or(0, Vec::len);
+        let transposed = (0..width)
+            .map(|x| rows.iter().map(|row| row[x]).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.parser.parse(&transposed)
+    }
+}
+
+/// A `&str` parser that splits the input into blocks of columns separated by blank
+/// columns and applies an inner [`StrParser`] to each block.
+///
+/// Constructed via [`StrParser::columns_split_on_blank`].
+pub struct ColumnsSplitOnBlank<P> {
+    parser: P,
+}
+
+impl<P, T> Parser<&str> for ColumnsSplitOnBlank<P>
+where
+    P: StrParser<Output = T>,
+{
+    type Output = Vec<T>;
+
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        let rows = padded_char_rows(input);
+        let width = rows.first().map_or(0, Vec::len);
+        let is_blank = |x: usize| rows.iter().all(|row| row[x].is_whitespace());
+
+        let mut outputs = Vec::new();
+        let mut x = 0;
+        while x < width {
+            if is_blank(x) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < width && !is_blank(x) {
+                x += 1;
+            }
+            let block = rows
+                .iter()
+                .map(|row| row[start..x].iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            outputs.push(self.parser.parse(&block)?);
+        }
+        Ok(outputs)
+    }
+}
+
+/// Splits `input` into lines of characters, padding each with spaces to the width of the
+/// longest line.
+fn padded_char_rows(input: &str) -> Vec<Vec<char>> {
+    let mut rows: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, ' ');
+    }
+    rows
+}
+
+/// A `&str` parser that splits the input into fields of fixed character widths and
+/// applies an inner [`StrParser`] to each field.
+///
+/// Constructed via [`StrParser::fixed_width`].
+pub struct FixedWidth<P> {
+    parser: P,
+    widths: Vec<usize>,
+}
+
+impl<P, T> Parser<&str> for FixedWidth<P>
+where
+    P: StrParser<Output = T>,
+{
+    type Output = Vec<T>;
+
+    fn parse(&self, input: &str) -> Result<Self::Output, ParseError> {
+        let total_width: usize = self.widths.iter().sum();
+        let last_width = self.widths.last().copied().unwrap_or(0);
+        // The final field may be cut short, but must hold at least one character.
+        let min_width = total_width - last_width + usize::from(last_width > 0);
+        let len = input.chars().count();
+        let expected = if len < min_width {
+            min_width
+        } else if len > total_width {
+            total_width
+        } else {
+            len
+        };
+        if expected != len {
+            return Err(ParseError::WrongLength {
+                expected,
+                got: len,
+                input: input.to_string(),
+            });
+        }
+
+        let mut rest = input;
+        self.widths
+            .iter()
+            .map(|&width| {
+                let split = rest
+                    .char_indices()
+                    .nth(width)
+                    .map_or(rest.len(), |(i, _)| i);
+                let (field, remainder) = rest.split_at(split);
+                rest = remainder;
+                self.parser.parse(field)
+            })
+            .collect()
+    }
+}
+
+/// Yields the blocks of `input` separated by one or more blank lines, each without its
+/// final line ending. Lines containing only whitespace count as blank.
+///
+/// Used by [`StrParser::blocks`] and [`sections!`](crate::sections).
+#[doc(hidden)]
+pub fn split_blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.split_inclusive('\n');
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let mut block: Option<(usize, usize)> = None;
+        for line in lines.by_ref() {
+            let start = offset;
+            offset += line.len();
+            let content = line.strip_suffix('\n').unwrap_or(line);
+            let content = content.strip_suffix('\r').unwrap_or(content);
+            if !content.trim().is_empty() {
+                let block_start = block.map_or(start, |(block_start, _)| block_start);
+                block = Some((block_start, start + content.len()));
+            } else if block.is_some() {
+                break;
+            }
+        }
+        block.map(|(start, end)| &input[start..end])
+    })
+}
+
+/// Parses a fixed number of blank-line separated blocks, each with its own parser,
+/// into a tuple.
+///
+/// Blocks are split as in [`StrParser::blocks`]. Returns
+/// [`ParseError::WrongSectionCount`] if the input does not contain exactly one block
+/// per parser. Each parser expression is evaluated once, when the macro is, and up to
+/// 16 sections are supported.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::sections;
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::{Parser, StrParser};
+/// let p = sections!(
+///     parser::from_str::<u32>.lines(),
+///     parser::as_string,
+/// );
+/// assert_eq!(p.parse("1\n2\n\nend\n"), Ok((vec![1, 2], "end".to_string())));
+/// assert!(p.parse("1\n2").is_err());
+/// ```
+#[macro_export]
+macro_rules! sections {
+    ($($p:expr),+ $(,)?) => {
+        $crate::sections!(
+            @bind [] [$($p),+]
+            [p0 p1 p2 p3 p4 p5 p6 p7 p8 p9 p10 p11 p12 p13 p14 p15]
+        )
+    };
+    // Pair each parser with a name so it is built once, outside the returned closure.
+    (@bind [$($name:ident = $bound:expr),*] [$p:expr $(, $rest:expr)*] [$next:ident $($names:ident)*]) => {
+        $crate::sections!(@bind [$($name = $bound,)* $next = $p] [$($rest),*] [$($names)*])
+    };
+    (@bind [$($name:ident = $bound:expr),+] [] [$($unused:ident)*]) => {{
+        $(let $name = $bound;)+
+        move |input: &str| {
+            let blocks: Vec<&str> = $crate::utils::parser::split_blocks(input).collect();
+            let expected = [$(stringify!($name)),+].len();
+            if blocks.len() != expected {
+                return Err($crate::utils::parser::ParseError::WrongSectionCount {
+                    expected,
+                    got: blocks.len(),
+                });
+            }
+            let mut blocks = blocks.into_iter();
+            Ok(($($crate::utils::parser::Parser::parse(&$name, blocks.next().unwrap())?,)+))
+        }
+    }};
+}
+
+/// Constructs a `&str -> Result` parser from a set of whole-token-to-value mappings.
+///
+/// The string counterpart to [`char_match!`](crate::char_match): each arm of the form
+/// `"token" => expr` is compiled into a `match` branch, and the *entire* input must equal
+/// one of the tokens. Anything else produces a [`ParseError::NoMatch`] listing the
+/// expected alternatives.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::str_match;
+/// # use aoc_lib::utils::parser::{Parser, StrParser};
+/// let p = str_match!("on" => true, "off" => false).split(",");
+/// assert_eq!(p.parse("on,off,on"), Ok(vec![true, false, true]));
+/// assert!(p.parse("on,of").is_err());
+/// ```
+#[macro_export]
+macro_rules! str_match {
+    ($($s:literal => $val:expr),+ $(,)?) => {
+        |s: &str| match s {
+            $($s => Ok($val),)+
+            _ => Err($crate::utils::parser::ParseError::NoMatch {
+                expected: vec![$($s.to_string()),+],
+                input: s.to_string(),
+            })
+        }
+    };
+}
+
+/// Constructs a prefix-matching parser from a set of token-to-value mappings.
+///
+/// Unlike [`str_match!`](crate::str_match), the input only needs to *start* with one of
+/// the tokens. The parser consumes the longest matching token and returns its value
+/// together with the unconsumed remainder of the input, so operators like `<` and `<=`
+/// can be listed in any order. Returns [`ParseError::NoMatch`] if no token matches.
+///
+/// # Example
+/// ```
+/// # use aoc_lib::str_prefix_match;
+/// # use aoc_lib::utils::parser;
+/// # use aoc_lib::utils::parser::Parser;
+/// # #[derive(Debug, PartialEq)]
+/// # enum Cmp { Lt, Le }
+/// let p = str_prefix_match!("<" => Cmp::Lt, "<=" => Cmp::Le);
+/// assert_eq!(p.parse("<=5"), Ok((Cmp::Le, "5")));
+/// assert_eq!(p.parse("<5"), Ok((Cmp::Lt, "5")));
+/// assert!(p.parse(">5").is_err());
+///
+/// let command = str_prefix_match!("forward " => (1, 0), "down " => (0, 1))
+///     .and_then(|(dir, rest)| Ok((dir, parser::from_str::<i32>(rest)?)));
+/// assert_eq!(command.parse("down 5"), Ok(((0, 1), 5)));
+/// ```
+#[macro_export]
+macro_rules! str_prefix_match {
+    ($($s:literal => $val:expr),+ $(,)?) => {
+        $crate::utils::parser::prefix_parser(|s: &str| {
+            let token = [$($s),+]
+                .into_iter()
+                .filter(|token| s.starts_with(token))
+                .max_by_key(|token| token.len());
+            match token {
+                $(Some($s) => Ok(($val, &s[$s.len()..])),)+
+                _ => Err($crate::utils::parser::ParseError::NoMatch {
+                    expected: vec![$($s.to_string()),+],
+                    input: s.to_string(),
+                }),
+            }
+        })
+    };
+}
+
+/// Pins a closure to the higher-ranked signature of a prefix parser, so that the
+/// remainder it returns borrows from its input.
+///
+/// Used by [`str_prefix_match!`](crate::str_prefix_match); closures cannot otherwise
+/// express a return type whose lifetime is tied to their argument.
+#[doc(hidden)]
+pub fn prefix_parser<T, F>(f: F) -> F
+where
+    F: for<'a> Fn(&'a str) -> Result<(T, &'a str), ParseError>,
+{
+    f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parser;
+    use crate::utils::property::{self, ints, vecs};
+    use itertools::Itertools;
+
+    #[test]
+    fn test_split_round_trip() {
+        property::round_trip(
+            &vecs(ints(0..=u32::MAX), 1..=20),
+            |nums| nums.iter().join(", "),
+            from_str::<u32>.split(", "),
+        );
+    }
+
+    #[test]
+    fn test_ints_array_round_trip() {
+        property::round_trip(
+            &vecs(ints(i32::MIN..=i32::MAX), 4..=4),
+            |nums| nums.iter().join("-"),
+            ints_array::<i32, 4>().map(Vec::from),
+        );
+    }
+
+    #[test]
+    fn test_split_array_round_trip() {
+        property::round_trip(
+            &property::arrays::<_, 4>(ints(0..=u32::MAX)),
+            |nums| nums.iter().join(","),
+            from_str::<u32>.split_array::<4>(","),
+        );
+    }
+
+    #[test]
+    fn test_lines_and_split_whitespace_round_trip() {
+        property::round_trip(
+            &vecs(vecs(ints(0..=999u16), 1..=8), 1..=8),
+            |rows| rows.iter().map(|row| row.iter().join("  ")).join("\n"),
+            from_str::<u16>.split_whitespace().lines(),
+        );
+    }
+
+    #[test]
+    fn test_blocks_round_trip() {
+        property::round_trip(
+            &vecs(vecs(ints(0..=9u8), 1..=4), 0..=6),
+            |blocks| {
+                blocks
+                    .iter()
+                    .map(|block| block.iter().join("\r\n"))
+                    .join("\r\n\r\n")
+            },
+            from_str::<u8>.lines().blocks(),
+        );
+    }
+
+    #[test]
+    fn test_sections_builds_parsers_once() {
+        let builds = std::cell::Cell::new(0);
+        let counted = || {
+            builds.set(builds.get() + 1);
+            from_str::<u32>
+        };
+        let p = crate::sections!(counted(), counted().lines());
+        for _ in 0..3 {
+            assert_eq!(p.parse("1\n\n2\n3"), Ok((1, vec![2, 3])));
+        }
+        assert_eq!(builds.get(), 2);
+    }
+
+    #[test]
+    fn test_blocks_whitespace_separator() {
+        let p = from_str::<u8>.lines().blocks();
+        assert_eq!(p.parse("1\n  \n2"), Ok(vec![vec![1], vec![2]]));
+        assert_eq!(
+            p.parse("1\n2\n\t\r\n \n3\n \n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn test_fixed_width_lengths() {
+        let wrong_length = |expected, input: &str| {
+            Err(ParseError::WrongLength {
+                expected,
+                got: input.chars().count(),
+                input: input.to_string(),
+            })
+        };
+        let trailing_empty = parser::as_string.fixed_width(&[3, 0]);
+        assert_eq!(
+            trailing_empty.parse("  1"),
+            Ok(vec!["  1".to_string(), String::new()])
+        );
+        assert_eq!(trailing_empty.parse("  1 "), wrong_length(3, "  1 "));
+        assert_eq!(trailing_empty.parse(" 1"), wrong_length(3, " 1"));
+
+        let p = parser::as_string.fixed_width(&[3, 2, 4]);
+        assert_eq!(p.parse("  1 2"), wrong_length(6, "  1 2"));
+        assert_eq!(p.parse("  1 2 3456"), wrong_length(9, "  1 2 3456"));
+    }
+
+    #[test]
+    fn test_borrowed_outputs() {
+        let input = "a,b\nc\n\nd e";
+        assert_eq!(
+            parser::as_str.split(",").lines().blocks().parse(input),
+            Ok(vec![vec![vec!["a", "b"], vec!["c"]], vec![vec!["d e"]]])
+        );
+        assert_eq!(
+            parser::as_str.split_whitespace().parse("d  e"),
+            Ok(vec!["d", "e"])
+        );
+        assert_eq!(
+            split_pair(parser::as_str, parser::as_str, "-").parse("x-y"),
+            Ok(("x", "y"))
+        );
+        assert_eq!(
+            lsplit_once(parser::as_str, parser::as_str, ":").parse("k:v:1"),
+            Ok(("k", "v:1"))
+        );
+    }
+
+    #[test]
+    fn test_rsplit_once_missing_separator() {
+        assert_eq!(
+            rsplit_once(parser::as_str, from_str::<u8>, "\n").parse("1 2"),
+            Err(ParseError::MissingSeparator {
+                separator: "\n".to_string(),
+                input: "1 2".to_string(),
+            })
+        );
     }
 }
@@ -0,0 +1,126 @@
+use crate::utils::parser::{ParseError, Parser};
+use std::fmt::Debug;
+use std::ops::RangeBounds;
+
+/// A parser that rejects outputs of an inner parser which fail a predicate.
+///
+/// Constructed via [`Parser::filter`].
+pub struct Filter<P, F> {
+    pub(super) parser: P,
+    pub(super) predicate: F,
+    pub(super) message: String,
+}
+
+impl<I, P, F> Parser<I> for Filter<P, F>
+where
+    P: Parser<I>,
+    P::Output: Debug,
+    F: Fn(&P::Output) -> bool,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        let output = self.parser.parse(input)?;
+        if (self.predicate)(&output) {
+            Ok(output)
+        } else {
+            Err(ParseError::Rejected {
+                message: self.message.clone(),
+                value: format!("{output:?}"),
+            })
+        }
+    }
+}
+
+/// A parser that requires the `Vec` produced by an inner parser to have a fixed length.
+///
+/// Constructed via [`Parser::ensure_len`].
+pub struct EnsureLen<P> {
+    pub(super) parser: P,
+    pub(super) len: usize,
+}
+
+impl<I, P, T> Parser<I> for EnsureLen<P>
+where
+    P: Parser<I, Output = Vec<T>>,
+{
+    type Output = Vec<T>;
+
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        let items = self.parser.parse(input)?;
+        if items.len() == self.len {
+            Ok(items)
+        } else {
+            Err(ParseError::WrongItemCount {
+                expected: self.len,
+                got: items.len(),
+            })
+        }
+    }
+}
+
+/// A parser that requires every row of the nested `Vec` produced by an inner parser to
+/// have the same length.
+///
+/// Constructed via [`Parser::ensure_rectangular`].
+pub struct EnsureRectangular<P> {
+    pub(super) parser: P,
+}
+
+impl<I, P, T> Parser<I> for EnsureRectangular<P>
+where
+    P: Parser<I, Output = Vec<Vec<T>>>,
+{
+    type Output = Vec<Vec<T>>;
+
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        let rows = self.parser.parse(input)?;
+        check_rectangular(&rows)?;
+        Ok(rows)
+    }
+}
+
+/// Returns [`ParseError::Ragged`] naming the first row whose length differs from the
+/// first row's, numbered from 1 like the lines in [`ParseError::Many`].
+pub(crate) fn check_rectangular<T>(rows: &[Vec<T>]) -> Result<(), ParseError> {
+    let Some(width) = rows.first().map(Vec::len) else {
+        return Ok(());
+    };
+    match rows.iter().position(|row| row.len() != width) {
+        Some(i) => Err(ParseError::Ragged {
+            row: i + 1,
+            expected: width,
+            got: rows[i].len(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// A parser that requires the output of an inner parser to lie within a range.
+///
+/// Constructed via [`Parser::in_range`].
+pub struct InRange<P, R> {
+    pub(super) parser: P,
+    pub(super) range: R,
+}
+
+impl<I, P, R> Parser<I> for InRange<P, R>
+where
+    P: Parser<I>,
+    P::Output: PartialOrd + Debug,
+    R: RangeBounds<P::Output> + Debug,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> Result<Self::Output, ParseError> {
+        let output = self.parser.parse(input)?;
+        if self.range.contains(&output) {
+            Ok(output)
+        } else {
+            Err(ParseError::OutOfRange {
+                value: format!("{output:?}"),
+                range: format!("{:?}", self.range),
+            })
+        }
+    }
+}
@@ -0,0 +1,362 @@
+//! A minimal property-based testing harness, used to check that parsers round-trip the
+//! values their paired printers render.
+//!
+//! Values are drawn from a [`Strategy`] using a deterministic [`Rng`]. When a property
+//! fails, the harness repeatedly replaces the failing value with a simpler one from
+//! [`Strategy::shrink`] that still fails, and reports the minimal case it reaches.
+
+use crate::utils::parser::Parser;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// Number of random cases checked by [`forall`].
+const CASES: usize = 256;
+/// Upper bound on shrinking steps, in case a strategy shrinks in circles.
+const MAX_SHRINKS: usize = 1000;
+/// Seed used unless overridden by the `PROPERTY_SEED` environment variable.
+const DEFAULT_SEED: u64 = 0x5eed_1234_abcd_0001;
+
+/// A small, deterministic SplitMix64 pseudo-random number generator.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed value in `0..n`. `n` must be non-zero.
+    pub fn below(&mut self, n: u128) -> u128 {
+        let wide = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+        wide % n
+    }
+}
+
+/// A recipe for generating random values of a type and simplifying failing ones.
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Returns simpler variants of `value`, most aggressive simplifications first.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/// Checks `property` against [`CASES`] generated values, panicking with a shrunk
+/// counterexample on the first failure.
+pub fn forall<S: Strategy>(strategy: &S, property: impl Fn(&S::Value) -> Result<(), String>) {
+    let seed = std::env::var("PROPERTY_SEED")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+    let mut rng = Rng::new(seed);
+
+    for case in 0..CASES {
+        let value = strategy.generate(&mut rng);
+        let Err(message) = property(&value) else {
+            continue;
+        };
+
+        let (minimal, message, shrinks) = shrink_failure(strategy, &property, value, message);
+        panic!(
+            "property failed on case {case} (seed {seed}), shrunk {shrinks} times\n\
+             minimal input: {minimal:?}\n{message}"
+        );
+    }
+}
+
+/// Repeatedly replaces a failing `value` with its first shrink that still fails.
+fn shrink_failure<S: Strategy>(
+    strategy: &S,
+    property: impl Fn(&S::Value) -> Result<(), String>,
+    mut value: S::Value,
+    mut message: String,
+) -> (S::Value, String, usize) {
+    let mut shrinks = 0;
+    'shrinking: while shrinks < MAX_SHRINKS {
+        for candidate in strategy.shrink(&value) {
+            if let Err(candidate_message) = property(&candidate) {
+                value = candidate;
+                message = candidate_message;
+                shrinks += 1;
+                continue 'shrinking;
+            }
+        }
+        break;
+    }
+    (value, message, shrinks)
+}
+
+/// Checks that `parser` recovers every generated value from the text `print` renders
+/// for it.
+pub fn round_trip<S, P>(strategy: &S, print: impl Fn(&S::Value) -> String, parser: P)
+where
+    S: Strategy,
+    S::Value: PartialEq,
+    P: for<'a> Parser<&'a str, Output = S::Value>,
+{
+    forall(strategy, |value| {
+        let text = print(value);
+        match parser.parse(&text) {
+            Ok(parsed) if parsed == *value => Ok(()),
+            result => Err(format!("{text:?} parsed as {result:?}")),
+        }
+    });
+}
+
+/// Integer types that [`ints`] can generate.
+pub trait Int: Copy + Debug {
+    fn to_i128(self) -> i128;
+    fn from_i128(n: i128) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl Int for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(n: i128) -> Self {
+                    n as $t
+                }
+            }
+        )+
+    };
+}
+
+impl_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Generates integers uniformly from a range, shrinking towards zero (or the bound
+/// nearest to it).
+pub struct Ints<T> {
+    range: RangeInclusive<T>,
+}
+
+pub fn ints<T: Int>(range: RangeInclusive<T>) -> Ints<T> {
+    Ints { range }
+}
+
+impl<T: Int> Strategy for Ints<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        let (lo, hi) = (self.range.start().to_i128(), self.range.end().to_i128());
+        T::from_i128(lo + rng.below((hi - lo + 1) as u128) as i128)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let (lo, hi) = (self.range.start().to_i128(), self.range.end().to_i128());
+        let target = 0.clamp(lo, hi);
+        let value = value.to_i128();
+
+        // Halve the distance to the target repeatedly, so large values shrink quickly
+        let mut candidates = Vec::new();
+        let mut distance = value - target;
+        while distance != 0 {
+            candidates.push(T::from_i128(value - distance));
+            distance /= 2;
+        }
+        candidates
+    }
+}
+
+/// Generates one of a fixed set of values, shrinking towards those listed first.
+pub struct OneOf<T> {
+    values: Vec<T>,
+}
+
+pub fn one_of<T: Clone + Debug + PartialEq>(values: &[T]) -> OneOf<T> {
+    OneOf {
+        values: values.to_vec(),
+    }
+}
+
+impl<T: Clone + Debug + PartialEq> Strategy for OneOf<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        self.values[rng.below(self.values.len() as u128) as usize].clone()
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let index = self.values.iter().position(|v| v == value).unwrap_or(0);
+        self.values[..index].to_vec()
+    }
+}
+
+/// Generates `Vec`s with a length drawn from a range, shrinking by removing elements
+/// and then by shrinking individual elements.
+pub struct Vecs<S> {
+    element: S,
+    len: RangeInclusive<usize>,
+}
+
+pub fn vecs<S: Strategy>(element: S, len: RangeInclusive<usize>) -> Vecs<S> {
+    Vecs { element, len }
+}
+
+impl<S: Strategy> Strategy for Vecs<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = ints(self.len.clone()).generate(rng);
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let min_len = *self.len.start();
+        let mut candidates = Vec::new();
+
+        // Remove progressively smaller chunks, from the largest the length allows
+        let mut chunk = value.len().saturating_sub(min_len);
+        while chunk > 0 {
+            for start in (0..=value.len() - chunk).step_by(chunk) {
+                let mut smaller = value[..start].to_vec();
+                smaller.extend_from_slice(&value[start + chunk..]);
+                candidates.push(smaller);
+            }
+            chunk /= 2;
+        }
+
+        for (i, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[i] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/// Generates fixed-size arrays, shrinking one element at a time.
+pub struct Arrays<S, const N: usize> {
+    element: S,
+}
+
+pub fn arrays<S: Strategy, const N: usize>(element: S) -> Arrays<S, N> {
+    Arrays { element }
+}
+
+impl<S: Strategy, const N: usize> Strategy for Arrays<S, N> {
+    type Value = [S::Value; N];
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        std::array::from_fn(|_| self.element.generate(rng))
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = Vec::new();
+        for (i, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[i] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/// Generates non-empty rectangular `Vec<Vec<_>>`s, shrinking by dropping trailing rows
+/// and columns and then by shrinking individual cells.
+pub struct Rectangles<S> {
+    cell: S,
+    max_size: usize,
+}
+
+pub fn rectangles<S: Strategy>(cell: S, max_size: usize) -> Rectangles<S> {
+    Rectangles { cell, max_size }
+}
+
+impl<S: Strategy> Strategy for Rectangles<S> {
+    type Value = Vec<Vec<S::Value>>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let height = ints(1..=self.max_size).generate(rng);
+        let width = ints(1..=self.max_size).generate(rng);
+        (0..height)
+            .map(|_| (0..width).map(|_| self.cell.generate(rng)).collect())
+            .collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = Vec::new();
+        if value.len() > 1 {
+            candidates.push(value[..value.len() - 1].to_vec());
+        }
+        if value[0].len() > 1 {
+            let narrower = value.iter().map(|row| row[..row.len() - 1].to_vec());
+            candidates.push(narrower.collect());
+        }
+        for (y, row) in value.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                for simpler in self.cell.shrink(cell) {
+                    let mut candidate = value.clone();
+                    candidate[y][x] = simpler;
+                    candidates.push(candidate);
+                }
+            }
+        }
+        candidates
+    }
+}
+
+impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let shrink_a = self.0.shrink(a).into_iter().map(|a| (a, b.clone()));
+        let shrink_b = self.1.shrink(b).into_iter().map(|b| (a.clone(), b));
+        shrink_a.chain(shrink_b).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let payload = std::panic::catch_unwind(f).unwrap_err();
+        payload.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn test_shrinks_int_to_boundary() {
+        let message = failure_message(|| {
+            forall(&ints(0..=1_000_000u64), |&n| match n < 1234 {
+                true => Ok(()),
+                false => Err("too big".to_string()),
+            })
+        });
+        assert!(message.contains("minimal input: 1234\n"), "{message}");
+    }
+
+    #[test]
+    fn test_shrinks_vec_to_single_offending_element() {
+        let message = failure_message(|| {
+            forall(&vecs(ints(0..=100u32), 0..=20), |v| {
+                match v.iter().all(|&n| n < 50) {
+                    true => Ok(()),
+                    false => Err("element too big".to_string()),
+                }
+            })
+        });
+        assert!(message.contains("minimal input: [50]\n"), "{message}");
+    }
+}
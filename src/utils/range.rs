@@ -5,25 +5,60 @@ use crate::utils::parser::{ParseError, Parser, StrParser};
 use std::cmp;
 use std::cmp::Ordering;
 use std::error::Error;
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
 use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy)]
+/// Whether a [`Range`] includes its end bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeKind {
+    /// Includes `end`, as in `3-7`, `3..=7` or `3 to 7`.
+    Inclusive,
+    /// Excludes `end`, as in `3..7`.
+    HalfOpen,
+}
+
+/// The delimiters accepted by [`Range::from_str`], longest first.
+pub const DEFAULT_DELIMITERS: &[(&str, RangeKind)] = &[
+    ("..=", RangeKind::Inclusive),
+    (" to ", RangeKind::Inclusive),
+    ("..", RangeKind::HalfOpen),
+    ("-", RangeKind::Inclusive),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Range<T> {
     start: T,
     end: T,
+    kind: RangeKind,
 }
 
+/// The bounds of a range whose start came after its end.
 #[derive(Debug)]
-pub struct RangeError;
+pub struct RangeError<T> {
+    pub start: T,
+    pub end: T,
+}
 
-impl Display for RangeError {
+impl<T: Debug> Display for RangeError<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Range start must be <= end")
+        write!(
+            f,
+            "Range start must be <= end: {:?} > {:?}",
+            self.start, self.end
+        )
     }
 }
 
-impl Error for RangeError {}
+impl<T: Debug> Error for RangeError<T> {}
+
+impl<T: Debug> From<RangeError<T>> for ParseError {
+    fn from(err: RangeError<T>) -> Self {
+        ParseError::Rejected {
+            message: "range start must be <= end".to_string(),
+            value: format!("{:?} > {:?}", err.start, err.end),
+        }
+    }
+}
 
 impl<T> Range<T> {
     pub fn start(&self) -> &T {
@@ -33,27 +68,61 @@ impl<T> Range<T> {
     pub fn end(&self) -> &T {
         &self.end
     }
+
+    pub fn kind(&self) -> RangeKind {
+        self.kind
+    }
+
+    pub fn is_inclusive(&self) -> bool {
+        self.kind == RangeKind::Inclusive
+    }
 }
 
 impl<T: PartialOrd> Range<T> {
-    pub fn new(start: T, end: T) -> Result<Self, RangeError> {
+    pub fn new(start: T, end: T) -> Result<Self, RangeError<T>> {
+        Self::with_kind(start, end, RangeKind::Inclusive)
+    }
+
+    pub fn half_open(start: T, end: T) -> Result<Self, RangeError<T>> {
+        Self::with_kind(start, end, RangeKind::HalfOpen)
+    }
+
+    pub fn with_kind(start: T, end: T, kind: RangeKind) -> Result<Self, RangeError<T>> {
         if start <= end {
-            Ok(Range { start, end })
+            Ok(Range { start, end, kind })
         } else {
-            Err(RangeError)
+            Err(RangeError { start, end })
         }
     }
 
     pub fn between(a: T, b: T) -> Self {
+        let kind = RangeKind::Inclusive;
         if a.partial_cmp(&b).unwrap() != Ordering::Greater {
-            Range { start: a, end: b }
+            Range {
+                start: a,
+                end: b,
+                kind,
+            }
         } else {
-            Range { start: b, end: a }
+            Range {
+                start: b,
+                end: a,
+                kind,
+            }
+        }
+    }
+
+    /// Whether `x` comes before the end of the range, taking its [`RangeKind`] into
+    /// account.
+    fn ends_after(&self, x: &T) -> bool {
+        match self.kind {
+            RangeKind::Inclusive => x <= &self.end,
+            RangeKind::HalfOpen => x < &self.end,
         }
     }
 
     pub fn contains(&self, x: &T) -> bool {
-        &self.start <= x && x <= &self.end
+        &self.start <= x && self.ends_after(x)
     }
 
     pub fn contains_exclusive(&self, x: &T) -> bool {
@@ -61,51 +130,228 @@ impl<T: PartialOrd> Range<T> {
     }
 
     pub fn overlaps(&self, range: &Range<T>) -> bool {
-        self.start <= range.end && range.start <= self.end
+        range.ends_after(&self.start) && self.ends_after(&range.start)
     }
 
     pub fn overlaps_strictly(&self, range: &Range<T>) -> bool {
         self.start < range.end && range.start < self.end
     }
+
+    /// Parses a range whose bounds are parsed by `bound`, split on whichever of
+    /// `delimiters` appears first in the list and yields two parseable bounds.
+    ///
+    /// A delimiter at the very start of the input is never split on, and every later
+    /// occurrence is tried in turn, so signed bounds work even when the delimiter is
+    /// `-`. Returns [`ParseError::NoMatch`] if no delimiter is present, and
+    /// [`ParseError::Rejected`] if the start comes after the end. The delimiters are
+    /// copied when the parser is built, so they may come from runtime data.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc_lib::utils::parser;
+    /// # use aoc_lib::utils::parser::Parser;
+    /// # use aoc_lib::utils::range::{Range, RangeKind};
+    /// let p = Range::parser(parser::from_str::<i32>, &[(":", RangeKind::HalfOpen)]);
+    /// let range = p.parse("-10:-2").unwrap();
+    /// assert_eq!((*range.start(), *range.end()), (-10, -2));
+    /// assert!(!range.is_inclusive());
+    /// assert!(p.parse("3-7").is_err());
+    /// ```
+    pub fn parser<B>(
+        bound: B,
+        delimiters: &[(&str, RangeKind)],
+    ) -> impl StrParser<Output = Range<T>> + use<T, B>
+    where
+        T: Debug,
+        B: StrParser<Output = T>,
+    {
+        let delimiters: Vec<(String, RangeKind)> = delimiters
+            .iter()
+            .map(|&(delimiter, kind)| (delimiter.to_string(), kind))
+            .collect();
+        (move |input: &str| split_bounds(input, &bound, &delimiters)).and_then(
+            |(start, end, kind)| Range::with_kind(start, end, kind).map_err(ParseError::from),
+        )
+    }
+}
+
+/// Splits `input` into bounds parsed by `bound`, as described on [`Range::parser`].
+fn split_bounds<T>(
+    input: &str,
+    bound: &impl StrParser<Output = T>,
+    delimiters: &[(impl AsRef<str>, RangeKind)],
+) -> Result<(T, T, RangeKind), ParseError> {
+    let mut first_error = None;
+    for (delimiter, kind) in delimiters {
+        let delimiter = delimiter.as_ref();
+        for (i, _) in input.match_indices(delimiter).filter(|&(i, _)| i > 0) {
+            let (left, right) = (&input[..i], &input[i + delimiter.len()..]);
+            let bounds = bound
+                .parse(left)
+                .and_then(|start| Ok((start, bound.parse(right)?)));
+            match bounds {
+                Ok((start, end)) => return Ok((start, end, *kind)),
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+    }
+    Err(first_error.unwrap_or_else(|| ParseError::NoMatch {
+        expected: delimiters
+            .iter()
+            .map(|(d, _)| d.as_ref().to_string())
+            .collect(),
+        input: input.to_string(),
+    }))
 }
 
 impl<T: Ord + Copy> Range<T> {
+    /// The end bound and kind of whichever of `self` and `range` extends further.
+    fn furthest_end(&self, range: &Range<T>) -> (T, RangeKind) {
+        match self.end.cmp(&range.end) {
+            Ordering::Less => (range.end, range.kind),
+            Ordering::Greater => (self.end, self.kind),
+            Ordering::Equal if self.is_inclusive() || range.is_inclusive() => {
+                (self.end, RangeKind::Inclusive)
+            }
+            Ordering::Equal => (self.end, RangeKind::HalfOpen),
+        }
+    }
+
     pub fn merge(&mut self, range: Range<T>) {
+        (self.end, self.kind) = self.furthest_end(&range);
         self.start = cmp::min(self.start, range.start);
-        self.end = cmp::max(self.end, range.end);
     }
 
     pub fn merged_with(mut self, range: Range<T>) -> Self {
-        self.start = cmp::min(self.start, range.start);
-        self.end = cmp::max(self.end, range.end);
+        self.merge(range);
         self
     }
 }
 
 impl Range<u64> {
     pub fn iter(&self) -> impl Iterator<Item = u64> {
-        self.start..=self.end
+        (self.start..=self.end).take(self.num_elems())
     }
 
     pub fn num_elems(&self) -> usize {
-        (self.end - self.start + 1) as usize
+        match self.kind {
+            RangeKind::Inclusive => (self.end - self.start + 1) as usize,
+            RangeKind::HalfOpen => (self.end - self.start) as usize,
+        }
+    }
+}
+
+impl<T: Display> Display for Range<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            RangeKind::Inclusive => write!(f, "{}..={}", self.start, self.end),
+            RangeKind::HalfOpen => write!(f, "{}..{}", self.start, self.end),
+        }
     }
 }
 
 impl<T> FromStr for Range<T>
 where
-    T: PartialOrd,
+    T: PartialOrd + Debug,
     T: FromStr,
     T::Err: Display,
 {
     type Err = ParseError;
 
+    /// Parses a range using [`DEFAULT_DELIMITERS`], accepting `3-7`, `-10--2`, `3..=7`,
+    /// `3 to 7` and the half-open `3..7`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parser::from_str
-            .split_array("-")
-            .and_then(|[start, end]| {
-                Range::new(start, end).map_err(|err| ParseError::Other(err.to_string()))
-            })
-            .parse(s)
+        let (start, end, kind) = split_bounds(s, &parser::from_str, DEFAULT_DELIMITERS)?;
+        Ok(Range::with_kind(start, end, kind)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::property::{self, ints, one_of};
+
+    fn kind_strategy() -> impl property::Strategy<Value = RangeKind> {
+        one_of(&[RangeKind::Inclusive, RangeKind::HalfOpen])
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // Generated as (start, length) so that every value describes a valid range
+        const HALF: i64 = i64::MAX / 2;
+        property::round_trip(
+            &((ints(-HALF..=HALF), ints(0..=HALF)), kind_strategy()),
+            |&((start, len), kind)| {
+                Range::with_kind(start, start + len, kind)
+                    .unwrap()
+                    .to_string()
+            },
+            parser::from_str::<Range<i64>>
+                .map(|range| ((range.start, range.end - range.start), range.kind)),
+        );
+    }
+
+    #[test]
+    fn test_delimiter_syntaxes() {
+        let syntaxes = [
+            ("-5-3", -5, 3, RangeKind::Inclusive),
+            ("-10--2", -10, -2, RangeKind::Inclusive),
+            ("3..7", 3, 7, RangeKind::HalfOpen),
+            ("3..=7", 3, 7, RangeKind::Inclusive),
+            ("-7..-3", -7, -3, RangeKind::HalfOpen),
+            ("3 to 7", 3, 7, RangeKind::Inclusive),
+        ];
+        for (text, start, end, kind) in syntaxes {
+            assert_eq!(text.parse(), Ok(Range { start, end, kind }), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_runtime_delimiters() {
+        let p = {
+            let config = String::from("<>,~");
+            let delimiters: Vec<_> = config
+                .split(',')
+                .zip([RangeKind::HalfOpen, RangeKind::Inclusive])
+                .collect();
+            Range::parser(parser::from_str::<i32>, &delimiters)
+        };
+        assert_eq!(
+            p.parse("-3<>4"),
+            Range::half_open(-3, 4).map_err(ParseError::from)
+        );
+        assert_eq!(p.parse("1~1"), Range::new(1, 1).map_err(ParseError::from));
+        assert!(p.parse("1-2").is_err());
+    }
+
+    #[test]
+    fn test_rejects_reversed() {
+        property::forall(
+            &(ints(1..=u64::MAX), ints(1..=u64::MAX)),
+            |&(start, gap)| {
+                let text = format!("{start}-{}", start.saturating_sub(gap));
+                match text.parse::<Range<u64>>() {
+                    Err(ParseError::Rejected { .. }) => Ok(()),
+                    Err(err) => Err(format!("{text:?} failed with {err:?}")),
+                    Ok(range) => Err(format!("{text:?} parsed as {range:?}")),
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_half_open_semantics() {
+        let half_open = Range::half_open(3u64, 7).unwrap();
+        let inclusive = Range::new(7u64, 9).unwrap();
+        assert!(!half_open.contains(&7));
+        assert_eq!(half_open.num_elems(), 4);
+        assert_eq!(half_open.iter().collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+        assert!(!half_open.overlaps(&inclusive));
+        assert_eq!(
+            half_open.merged_with(Range::new(5, 7).unwrap()).num_elems(),
+            5
+        );
     }
 }
@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path cost that can be summed and compared, starting from [`Cost::ZERO`].
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(impl Cost for $t {
+            const ZERO: Self = 0;
+        })*
+    };
+}
+
+impl_cost!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Everything reachable from a search's start state: the cheapest known cost to
+/// each state, and the predecessor each state was first reached from along that
+/// cheapest path.
+#[derive(Clone, Debug)]
+pub struct SearchResult<S, C> {
+    pub start: S,
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> SearchResult<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Self {
+            distances: HashMap::from([(start.clone(), zero)]),
+            predecessors: HashMap::new(),
+            start,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// Walks the predecessor tree back from `target`, returning the path from the
+    /// start to `target` inclusive, or `None` if `target` was never reached.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start`, where every edge has cost 1.
+pub fn bfs<S, N, I>(start: S, mut neighbors: N) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let dist = result.distances[&state] + 1;
+        for next in neighbors(&state) {
+            if let Entry::Vacant(entry) = result.distances.entry(next.clone()) {
+                entry.insert(dist);
+                result.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    result
+}
+
+/// Heap entry ordered so that [`BinaryHeap`] pops the lowest `priority` first,
+/// without requiring the state itself to be ordered.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Best-first search shared by [`dijkstra`] and [`astar`]. Stops as soon as a
+/// state satisfying `is_goal` is popped, returning it.
+fn best_first<S, C, N, I, H, G>(
+    result: &mut SearchResult<S, C>,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<S>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut heap = BinaryHeap::from([Queued {
+        priority: heuristic(&result.start),
+        cost: C::ZERO,
+        state: result.start.clone(),
+    }]);
+
+    while let Some(Queued { cost, state, .. }) = heap.pop() {
+        if cost > result.distances[&state] {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(state);
+        }
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            if result
+                .distance(&next)
+                .is_some_and(|known| known <= next_cost)
+            {
+                continue;
+            }
+            result.distances.insert(next.clone(), next_cost);
+            result.predecessors.insert(next.clone(), state.clone());
+            heap.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm from `start`, exploring every reachable state. Edge costs
+/// must be non-negative.
+pub fn dijkstra<S, C, N, I>(start: S, neighbors: N) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut result = SearchResult::new(start, C::ZERO);
+    best_first(&mut result, neighbors, |_| C::ZERO, |_| false);
+    result
+}
+
+/// A* search from `start` to the first state satisfying `is_goal`, returning the
+/// path to it and its cost. `heuristic` must never overestimate the remaining
+/// cost for the result to be optimal.
+pub fn astar<S, C, N, I, H, G>(
+    start: S,
+    neighbors: N,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new(start, C::ZERO);
+    let goal = best_first(&mut result, neighbors, heuristic, is_goal)?;
+    let cost = result.distances[&goal];
+    Some((result.path_to(&goal)?, cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(state: &char) -> Vec<(char, u32)> {
+        match state {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('a', 7), ('c', 10), ('d', 15)],
+            'c' => vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)],
+            'd' => vec![('b', 15), ('c', 11), ('e', 6)],
+            'e' => vec![('d', 6), ('f', 9)],
+            'f' => vec![('a', 14), ('c', 2), ('e', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs('a', |s| graph(s).into_iter().map(|(next, _)| next));
+        assert_eq!(result.distance(&'e'), Some(2));
+        assert_eq!(result.path_to(&'e').map(|p| p.len()), Some(3));
+        assert_eq!(result.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(result.path_to(&'z'), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra('a', graph);
+        assert_eq!(result.distance(&'e'), Some(20));
+        assert_eq!(result.distance(&'d'), Some(20));
+        assert_eq!(result.path_to(&'e'), Some(vec!['a', 'c', 'f', 'e']));
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let all = dijkstra('a', graph);
+        for goal in ['a', 'b', 'c', 'd', 'e', 'f'] {
+            let (path, cost) = astar('a', graph, |_| 0, |&s| s == goal).unwrap();
+            assert_eq!(Some(cost), all.distance(&goal));
+            assert_eq!(path.first(), Some(&'a'));
+            assert_eq!(path.last(), Some(&goal));
+        }
+        assert_eq!(astar('a', graph, |_| 0, |&s| s == 'z'), None);
+    }
+}